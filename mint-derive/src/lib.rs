@@ -0,0 +1,236 @@
+/*!
+Derive macros for the mint math interoperability types.
+
+Use them through the `derive` feature of `mint`.
+*/
+#![deny(
+    missing_docs,
+    rust_2018_compatibility,
+    rust_2018_idioms,
+    future_incompatible,
+    nonstandard_style,
+    unused,
+    clippy::all
+)]
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Ident, Lit, Meta, NestedMeta, Type};
+
+/// Derives `IntoMint`, together with the `From` conversions both ways,
+/// for a struct with named fields.
+///
+/// The mint type is given by the `#[mint(...)]` attribute of the struct, such as
+/// `#[mint(Vector3)]`. Without type arguments it's parameterized by the type of
+/// the first field, and without a path it's looked up in the `mint` crate.
+///
+/// Fields are matched with the fields of the mint type by name, and converted
+/// with `Into`, so they may themselves be types deriving `IntoMint`.
+/// A field is matched with another name with `#[mint(rename = "x")]`.
+///
+/// For a `Quaternion`, the `x`, `y`, and `z` fields form the vector part,
+/// and the scalar part is the field named `w` or `s`,
+/// or the one marked with `#[mint(scalar)]`, wherever it's placed in the struct.
+#[proc_macro_derive(IntoMint, attributes(mint))]
+pub fn derive_into_mint(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+struct Field {
+    ident: Ident,
+    target: Ident,
+    scalar: bool,
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let named = match input.data {
+        Data::Struct(ref data) => match data.fields {
+            Fields::Named(ref named) => &named.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "IntoMint can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "IntoMint can only be derived for structs",
+            ))
+        }
+    };
+    let first_ty = match named.first() {
+        Some(field) => &field.ty,
+        None => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "IntoMint can't be derived for a struct without fields",
+            ))
+        }
+    };
+
+    let mut mint_ty = None;
+    for attr in input.attrs.iter().filter(|attr| attr.path.is_ident("mint")) {
+        if mint_ty.is_some() {
+            return Err(syn::Error::new_spanned(attr, "duplicate mint type"));
+        }
+        mint_ty = Some(attr.parse_args::<Type>()?);
+    }
+    let mint_ty = match mint_ty {
+        Some(ty) => complete_type(ty, first_ty),
+        None => {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "missing the mint type, such as `#[mint(Vector3)]`",
+            ))
+        }
+    };
+
+    let mut fields = Vec::new();
+    for field in named {
+        let ident = field.ident.clone().unwrap();
+        let mut target = ident.clone();
+        let mut scalar = false;
+        for attr in field.attrs.iter().filter(|attr| attr.path.is_ident("mint")) {
+            let list = match attr.parse_meta()? {
+                Meta::List(list) => list,
+                meta => return Err(syn::Error::new_spanned(meta, "expected `mint(...)`")),
+            };
+            for nested in list.nested {
+                match nested {
+                    NestedMeta::Meta(Meta::NameValue(ref pair)) if pair.path.is_ident("rename") => {
+                        target = match pair.lit {
+                            Lit::Str(ref name) => name.parse()?,
+                            ref lit => {
+                                return Err(syn::Error::new_spanned(lit, "expected a string"))
+                            }
+                        };
+                    }
+                    NestedMeta::Meta(Meta::Path(ref path)) if path.is_ident("scalar") => {
+                        scalar = true;
+                    }
+                    ref other => {
+                        return Err(syn::Error::new_spanned(
+                            other,
+                            "expected `rename` or `scalar`",
+                        ))
+                    }
+                }
+            }
+        }
+        fields.push(Field {
+            ident,
+            target,
+            scalar,
+        });
+    }
+
+    let (into_mint, from_mint) = if is_quaternion(&mint_ty) {
+        quaternion_conversions(input, &fields)?
+    } else {
+        let idents = fields.iter().map(|f| &f.ident).collect::<Vec<_>>();
+        let targets = fields.iter().map(|f| &f.target).collect::<Vec<_>>();
+        (
+            quote! { Self { #( #targets: value.#idents.into(), )* } },
+            quote! { Self { #( #idents: mint.#targets.into(), )* } },
+        )
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::mint::IntoMint for #name #ty_generics #where_clause {
+            type MintType = #mint_ty;
+        }
+
+        impl #impl_generics ::core::convert::From<#name #ty_generics> for #mint_ty #where_clause {
+            #[allow(clippy::useless_conversion)]
+            fn from(value: #name #ty_generics) -> Self {
+                #into_mint
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<#mint_ty> for #name #ty_generics #where_clause {
+            #[allow(clippy::useless_conversion)]
+            fn from(mint: #mint_ty) -> Self {
+                #from_mint
+            }
+        }
+    })
+}
+
+/// Resolves a bare type name in the `mint` crate, and parameterizes
+/// it by the type of the first field if it has no type arguments.
+fn complete_type(ty: Type, first_ty: &Type) -> Type {
+    let mut path = match ty {
+        Type::Path(ref path) if path.qself.is_none() => path.path.clone(),
+        _ => return ty,
+    };
+    if path.leading_colon.is_none() && path.segments.len() == 1 {
+        path = syn::parse_quote!(::mint::#path);
+    }
+    let last = path.segments.last_mut().unwrap();
+    if last.arguments.is_empty() {
+        last.arguments = syn::PathArguments::AngleBracketed(syn::parse_quote!(<#first_ty>));
+    }
+    Type::Path(syn::TypePath { qself: None, path })
+}
+
+fn is_quaternion(ty: &Type) -> bool {
+    match *ty {
+        Type::Path(ref path) => path.path.segments.last().unwrap().ident == "Quaternion",
+        _ => false,
+    }
+}
+
+fn quaternion_conversions(
+    input: &DeriveInput,
+    fields: &[Field],
+) -> syn::Result<(TokenStream, TokenStream)> {
+    let marked = fields.iter().filter(|f| f.scalar).collect::<Vec<_>>();
+    let scalar = match marked.len() {
+        0 => fields.iter().find(|f| f.target == "w" || f.target == "s"),
+        1 => Some(marked[0]),
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &marked[1].ident,
+                "only one field can be the scalar part",
+            ))
+        }
+    };
+    let scalar = match scalar {
+        Some(field) => field,
+        None => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "missing the scalar part, mark it with `#[mint(scalar)]`",
+            ))
+        }
+    };
+
+    let vector = fields
+        .iter()
+        .filter(|f| f.ident != scalar.ident)
+        .collect::<Vec<_>>();
+    let idents = vector.iter().map(|f| &f.ident).collect::<Vec<_>>();
+    let targets = vector.iter().map(|f| &f.target).collect::<Vec<_>>();
+    let scalar = &scalar.ident;
+    Ok((
+        quote! {
+            Self {
+                v: ::mint::Vector3 { #( #targets: value.#idents.into(), )* },
+                s: value.#scalar.into(),
+            }
+        },
+        quote! {
+            Self {
+                #( #idents: mint.v.#targets.into(), )*
+                #scalar: mint.s.into(),
+            }
+        },
+    ))
+}
@@ -0,0 +1,137 @@
+use crate::rotation::EulerAngles;
+use crate::{AsMint, AsMintMut, IntoMint};
+#[cfg(feature = "num-traits")]
+use num_traits::float::FloatCore;
+
+macro_rules! angle {
+    ($name:ident = $doc:expr) => {
+        #[doc = $doc]
+        #[derive(Clone, Copy, Debug, Default, Hash, PartialEq, PartialOrd, Eq, Ord)]
+        #[repr(transparent)]
+        pub struct $name<T>(pub T);
+
+        impl<T> IntoMint for $name<T> {
+            type MintType = $name<T>;
+        }
+
+        unsafe impl<T> AsMint for $name<T> {}
+
+        unsafe impl<T> AsMintMut for $name<T> {}
+
+        impl<T> AsRef<T> for $name<T> {
+            fn as_ref(&self) -> &T {
+                &self.0
+            }
+        }
+
+        impl<T> AsMut<T> for $name<T> {
+            fn as_mut(&mut self) -> &mut T {
+                &mut self.0
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<T> ::serde::Serialize for $name<T>
+        where
+            T: ::serde::Serialize,
+        {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: ::serde::Serializer,
+            {
+                self.0.serialize(serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de, T> ::serde::Deserialize<'de> for $name<T>
+        where
+            T: ::serde::Deserialize<'de>,
+        {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                T::deserialize(deserializer).map($name)
+            }
+        }
+    };
+}
+
+angle!(Rad = "Angle in radians.");
+angle!(Deg = "Angle in degrees.");
+
+#[cfg(feature = "num-traits")]
+impl<T: FloatCore> From<Deg<T>> for Rad<T> {
+    fn from(deg: Deg<T>) -> Self {
+        Rad(deg.0.to_radians())
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl<T: FloatCore> From<Rad<T>> for Deg<T> {
+    fn from(rad: Rad<T>) -> Self {
+        Deg(rad.0.to_degrees())
+    }
+}
+
+impl<T, B> From<[Rad<T>; 3]> for EulerAngles<T, B> {
+    fn from([a, b, c]: [Rad<T>; 3]) -> Self {
+        EulerAngles::from([a.0, b.0, c.0])
+    }
+}
+
+impl<T, B> From<EulerAngles<T, B>> for [Rad<T>; 3] {
+    fn from(euler: EulerAngles<T, B>) -> [Rad<T>; 3] {
+        [Rad(euler.a), Rad(euler.b), Rad(euler.c)]
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl<T: FloatCore, B> From<[Deg<T>; 3]> for EulerAngles<T, B> {
+    fn from([a, b, c]: [Deg<T>; 3]) -> Self {
+        EulerAngles::from([Rad::from(a), Rad::from(b), Rad::from(c)])
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl<T: FloatCore, B> From<EulerAngles<T, B>> for [Deg<T>; 3] {
+    fn from(euler: EulerAngles<T, B>) -> [Deg<T>; 3] {
+        let [a, b, c]: [Rad<T>; 3] = euler.into();
+        [Deg::from(a), Deg::from(b), Deg::from(c)]
+    }
+}
+
+/// Alternative serde representation of `EulerAngles` in degrees,
+/// which is easier to read and edit by hand.
+///
+/// Use it as `#[serde(with = "mint::serde_degrees")]` on an `EulerAngles` field.
+#[cfg(all(feature = "serde", feature = "num-traits"))]
+pub mod serde_degrees {
+    use super::Deg;
+    use crate::rotation::EulerAngles;
+    use num_traits::float::FloatCore;
+
+    /// Serializes the angles as an array of degrees.
+    pub fn serialize<T, B, S>(euler: &EulerAngles<T, B>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: FloatCore + ::serde::Serialize,
+        S: ::serde::Serializer,
+    {
+        let degrees = [
+            Deg(euler.a.to_degrees()),
+            Deg(euler.b.to_degrees()),
+            Deg(euler.c.to_degrees()),
+        ];
+        ::serde::Serialize::serialize(&degrees, serializer)
+    }
+
+    /// Deserializes the angles from an array of degrees.
+    pub fn deserialize<'de, T, B, D>(deserializer: D) -> Result<EulerAngles<T, B>, D::Error>
+    where
+        T: FloatCore + ::serde::Deserialize<'de>,
+        D: ::serde::Deserializer<'de>,
+    {
+        <[Deg<T>; 3] as ::serde::Deserialize<'de>>::deserialize(deserializer).map(EulerAngles::from)
+    }
+}
@@ -0,0 +1,122 @@
+#[cfg(feature = "num-traits")]
+use crate::matrix::{ColumnMatrix3, RowMatrix3};
+#[cfg(feature = "num-traits")]
+use crate::vector::Vector3;
+#[cfg(feature = "num-traits")]
+use core::convert::TryFrom;
+#[cfg(feature = "num-traits")]
+use num_traits::{One, Zero};
+
+vec!(
+    /// Intrinsic parameters of a pinhole camera, in pixels.
+    ///
+    /// They correspond to the camera matrix
+    /// ```text
+    /// | fx  skew cx |
+    /// | 0   fy   cy |
+    /// | 0   0    1  |
+    /// ```
+    CameraIntrinsics [
+        /// Focal length along the X axis.
+        fx,
+        /// Focal length along the Y axis.
+        fy,
+        /// Principal point X coordinate.
+        cx,
+        /// Principal point Y coordinate.
+        cy,
+        /// Skew between the X and Y axes, usually zero.
+        skew
+    ] = [T; 5]
+);
+
+vec!(
+    /// Brown-Conrady lens distortion coefficients,
+    /// in the order used by OpenCV.
+    BrownConradyDistortion [
+        /// First radial coefficient.
+        k1,
+        /// Second radial coefficient.
+        k2,
+        /// First tangential coefficient.
+        p1,
+        /// Second tangential coefficient.
+        p2,
+        /// Third radial coefficient.
+        k3
+    ] = [T; 5]
+);
+
+vec!(
+    /// Fisheye (Kannala-Brandt) lens distortion coefficients,
+    /// in the order used by OpenCV.
+    FisheyeDistortion [
+        /// First coefficient.
+        k1,
+        /// Second coefficient.
+        k2,
+        /// Third coefficient.
+        k3,
+        /// Fourth coefficient.
+        k4
+    ] = [T; 4]
+);
+
+#[cfg(feature = "num-traits")]
+impl<T: Zero + One> From<CameraIntrinsics<T>> for RowMatrix3<T> {
+    fn from(k: CameraIntrinsics<T>) -> Self {
+        RowMatrix3 {
+            x: Vector3 {
+                x: k.fx,
+                y: k.skew,
+                z: k.cx,
+            },
+            y: Vector3 {
+                x: T::zero(),
+                y: k.fy,
+                z: k.cy,
+            },
+            z: Vector3 {
+                x: T::zero(),
+                y: T::zero(),
+                z: T::one(),
+            },
+        }
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl<T: Zero + One> From<CameraIntrinsics<T>> for ColumnMatrix3<T> {
+    fn from(k: CameraIntrinsics<T>) -> Self {
+        RowMatrix3::from(k).into()
+    }
+}
+
+/// Fails if the matrix is not of the camera matrix form, returning it back.
+#[cfg(feature = "num-traits")]
+impl<T: Zero + One + PartialEq> TryFrom<RowMatrix3<T>> for CameraIntrinsics<T> {
+    type Error = RowMatrix3<T>;
+
+    fn try_from(m: RowMatrix3<T>) -> Result<Self, Self::Error> {
+        if !(m.y.x.is_zero() && m.z.x.is_zero() && m.z.y.is_zero() && m.z.z.is_one()) {
+            return Err(m);
+        }
+        Ok(CameraIntrinsics {
+            fx: m.x.x,
+            fy: m.y.y,
+            cx: m.x.z,
+            cy: m.y.z,
+            skew: m.x.y,
+        })
+    }
+}
+
+/// Fails if the matrix is not of the camera matrix form, returning it back.
+#[cfg(feature = "num-traits")]
+impl<T: Zero + One + PartialEq> TryFrom<ColumnMatrix3<T>> for CameraIntrinsics<T> {
+    type Error = ColumnMatrix3<T>;
+
+    fn try_from(m: ColumnMatrix3<T>) -> Result<Self, Self::Error> {
+        CameraIntrinsics::try_from(RowMatrix3::from(m)).map_err(ColumnMatrix3::from)
+    }
+}
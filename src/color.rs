@@ -0,0 +1,102 @@
+use crate::vector::{Vector3, Vector4};
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+
+/// Linear color space with sRGB primaries.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
+pub enum Linear {}
+/// Non-linear sRGB color space, with the sRGB transfer function applied.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
+pub enum Srgb {}
+
+/// Color channels that are not multiplied by alpha.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
+pub enum Straight {}
+/// Color channels that are already multiplied by alpha.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
+pub enum Premultiplied {}
+
+macro_rules! color {
+    ($(#[$attr:meta])* $name:ident < $($param:ident),* > [ $($field:ident),* ] = $fixed:ty, $vec:ident) => {
+        $(#[$attr])*
+        #[repr(C)]
+        #[allow(missing_docs)]
+        pub struct $name<T, $($param),*> {
+            $( pub $field: T, )*
+            /// Marker for the phantom color space and alpha mode.
+            pub marker: PhantomData<($($param,)*)>,
+        }
+
+        impl<T: Clone, $($param),*> Clone for $name<T, $($param),*> {
+            fn clone(&self) -> Self {
+                $name {
+                    $( $field: self.$field.clone(), )*
+                    marker: PhantomData,
+                }
+            }
+        }
+
+        impl<T: Copy, $($param),*> Copy for $name<T, $($param),*> {}
+
+        impl<T: fmt::Debug, $($param),*> fmt::Debug for $name<T, $($param),*> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify!($name))
+                    $( .field(stringify!($field), &self.$field) )*
+                    .field("marker", &self.marker)
+                    .finish()
+            }
+        }
+
+        impl<T: Hash, $($param),*> Hash for $name<T, $($param),*> {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                $( self.$field.hash(state); )*
+            }
+        }
+
+        impl<T: PartialEq, $($param),*> PartialEq for $name<T, $($param),*> {
+            fn eq(&self, other: &Self) -> bool {
+                AsRef::<$fixed>::as_ref(self) == AsRef::<$fixed>::as_ref(other)
+            }
+        }
+
+        impl<T: Eq, $($param),*> Eq for $name<T, $($param),*> {}
+
+        impl<T: PartialOrd, $($param),*> PartialOrd for $name<T, $($param),*> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                AsRef::<$fixed>::as_ref(self).partial_cmp(AsRef::<$fixed>::as_ref(other))
+            }
+        }
+
+        impl<T: Ord, $($param),*> Ord for $name<T, $($param),*> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                AsRef::<$fixed>::as_ref(self).cmp(AsRef::<$fixed>::as_ref(other))
+            }
+        }
+
+        fixed_conversions!($name<$($param),*> [$($field),*] + marker = $fixed);
+
+        impl<T, $($param),*> From<$vec<T>> for $name<T, $($param),*> {
+            fn from(v: $vec<T>) -> Self {
+                <$fixed>::from(v).into()
+            }
+        }
+
+        impl<T, $($param),*> From<$name<T, $($param),*>> for $vec<T> {
+            fn from(name: $name<T, $($param),*>) -> Self {
+                <$fixed>::from(name).into()
+            }
+        }
+    };
+}
+
+color!(
+    /// RGB color in the color space `S`, such as `Linear` or `Srgb`.
+    Rgb<S> [r, g, b] = [T; 3], Vector3
+);
+color!(
+    /// RGBA color in the color space `S`, such as `Linear` or `Srgb`,
+    /// with the alpha mode `A`, either `Straight` or `Premultiplied`.
+    Rgba<S, A> [r, g, b, a] = [T; 4], Vector4
+);
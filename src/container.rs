@@ -0,0 +1,108 @@
+use crate::into_mint::{FromMint, IntoMint};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ptr;
+
+/// Containers of `IntoMint` values that convert element-wise into the same
+/// containers of mint values.
+///
+/// `IntoMint` itself can't be implemented for them, since `From` can't be
+/// implemented between containers defined outside of mint.
+pub trait IntoMints {
+    /// The container of mint values.
+    type Mints;
+
+    /// Converts every element into its mint equivalent.
+    fn into_mints(self) -> Self::Mints;
+}
+
+/// Containers of `FromMint` values that can be constructed element-wise
+/// from the same containers of mint values.
+pub trait FromMints: IntoMints {
+    /// Constructs every element from its mint equivalent.
+    fn from_mints(mints: Self::Mints) -> Self;
+}
+
+/// Converts every element of an array, keeping the length.
+fn map_array<A, B, F: FnMut(A) -> B, const N: usize>(array: [A; N], mut f: F) -> [B; N] {
+    // If `f` panics, the remaining and the converted elements are leaked.
+    let array = ManuallyDrop::new(array);
+    let mut out = MaybeUninit::<[B; N]>::uninit();
+    let out_ptr = out.as_mut_ptr() as *mut B;
+    for (i, a) in array.iter().enumerate() {
+        unsafe { out_ptr.add(i).write(f(ptr::read(a))) };
+    }
+    unsafe { out.assume_init() }
+}
+
+impl<T: IntoMint, const N: usize> IntoMints for [T; N] {
+    type Mints = [T::MintType; N];
+
+    fn into_mints(self) -> Self::Mints {
+        map_array(self, Into::into)
+    }
+}
+
+impl<T: FromMint, const N: usize> FromMints for [T; N] {
+    fn from_mints(mints: Self::Mints) -> Self {
+        map_array(mints, T::from_mint)
+    }
+}
+
+impl<T: IntoMint> IntoMints for Option<T> {
+    type Mints = Option<T::MintType>;
+
+    fn into_mints(self) -> Self::Mints {
+        self.map(Into::into)
+    }
+}
+
+impl<T: FromMint> FromMints for Option<T> {
+    fn from_mints(mints: Self::Mints) -> Self {
+        mints.map(T::from_mint)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: IntoMint> IntoMints for Vec<T> {
+    type Mints = Vec<T::MintType>;
+
+    fn into_mints(self) -> Self::Mints {
+        self.into_iter().map(Into::into).collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: FromMint> FromMints for Vec<T> {
+    fn from_mints(mints: Self::Mints) -> Self {
+        mints.into_iter().map(T::from_mint).collect()
+    }
+}
+
+macro_rules! tuple {
+    ($($name:ident),*) => {
+        impl<$($name: IntoMint),*> IntoMints for ($($name,)*) {
+            type Mints = ($($name::MintType,)*);
+
+            #[allow(non_snake_case)]
+            fn into_mints(self) -> Self::Mints {
+                let ($($name,)*) = self;
+                ($($name.into(),)*)
+            }
+        }
+
+        impl<$($name: FromMint),*> FromMints for ($($name,)*) {
+            #[allow(non_snake_case)]
+            fn from_mints(mints: Self::Mints) -> Self {
+                let ($($name,)*) = mints;
+                ($($name::from_mint($name),)*)
+            }
+        }
+    };
+}
+
+tuple!(A);
+tuple!(A, B);
+tuple!(A, B, C);
+tuple!(A, B, C, D);
@@ -0,0 +1,213 @@
+use crate::matrix::{ColumnMatrix3, ColumnMatrix4, RowMatrix3, RowMatrix4};
+use crate::rotation::Quaternion;
+use crate::tagged::Tagged;
+use crate::vector::{Point3, Vector3};
+use core::ops::Neg;
+
+/// Signed axis of a coordinate system.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Axis {
+    /// Positive X axis.
+    X,
+    /// Positive Y axis.
+    Y,
+    /// Positive Z axis.
+    Z,
+    /// Negative X axis.
+    NegX,
+    /// Negative Y axis.
+    NegY,
+    /// Negative Z axis.
+    NegZ,
+}
+
+impl Axis {
+    fn index(self) -> usize {
+        match self {
+            Axis::X | Axis::NegX => 0,
+            Axis::Y | Axis::NegY => 1,
+            Axis::Z | Axis::NegZ => 2,
+        }
+    }
+
+    fn is_negative(self) -> bool {
+        match self {
+            Axis::X | Axis::Y | Axis::Z => false,
+            Axis::NegX | Axis::NegY | Axis::NegZ => true,
+        }
+    }
+}
+
+/// Convention of the axes in 3D space.
+pub trait CoordinateSystem {
+    /// Axes pointing right, up, and back (towards the viewer), in this order.
+    const RIGHT_UP_BACK: [Axis; 3];
+}
+
+/// Y axis up, right-handed: X right, Y up, Z back.
+/// Used by glTF and OpenGL.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
+pub enum YUpRightHanded {}
+/// Y axis up, left-handed: X right, Y up, Z forward.
+/// Used by Unity and Direct3D.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
+pub enum YUpLeftHanded {}
+/// Z axis up, right-handed: X right, Y forward, Z up.
+/// Used by Blender.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
+pub enum ZUpRightHanded {}
+/// Z axis up, left-handed: X forward, Y right, Z up.
+/// Used by Unreal Engine.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
+pub enum ZUpLeftHanded {}
+
+impl CoordinateSystem for YUpRightHanded {
+    const RIGHT_UP_BACK: [Axis; 3] = [Axis::X, Axis::Y, Axis::Z];
+}
+impl CoordinateSystem for YUpLeftHanded {
+    const RIGHT_UP_BACK: [Axis; 3] = [Axis::X, Axis::Y, Axis::NegZ];
+}
+impl CoordinateSystem for ZUpRightHanded {
+    const RIGHT_UP_BACK: [Axis; 3] = [Axis::X, Axis::Z, Axis::NegY];
+}
+impl CoordinateSystem for ZUpLeftHanded {
+    const RIGHT_UP_BACK: [Axis; 3] = [Axis::Y, Axis::Z, Axis::NegX];
+}
+
+/// Signed permutation taking the components of one coordinate system to another.
+struct Swizzle {
+    /// Source component for each destination component.
+    source: [usize; 3],
+    /// Whether each destination component is negated.
+    negate: [bool; 3],
+    /// Whether the handedness changes.
+    mirror: bool,
+}
+
+impl Swizzle {
+    fn new<F: CoordinateSystem, To: CoordinateSystem>() -> Self {
+        let mut swizzle = Swizzle {
+            source: [0; 3],
+            negate: [false; 3],
+            mirror: false,
+        };
+        for (from, to) in F::RIGHT_UP_BACK.iter().zip(To::RIGHT_UP_BACK.iter()) {
+            swizzle.source[to.index()] = from.index();
+            swizzle.negate[to.index()] = from.is_negative() != to.is_negative();
+        }
+        // The determinant is the product of the signs and the permutation parity.
+        let odd = swizzle.source == [1, 0, 2]
+            || swizzle.source == [2, 1, 0]
+            || swizzle.source == [0, 2, 1];
+        let negations = swizzle.negate.iter().filter(|&&n| n).count();
+        swizzle.mirror = odd != (negations % 2 == 1);
+        swizzle
+    }
+
+    fn apply<U: Clone>(&self, v: &[U; 3], neg: impl Fn(U) -> U) -> [U; 3] {
+        let component = |i: usize| {
+            let c = v[self.source[i]].clone();
+            if self.negate[i] {
+                neg(c)
+            } else {
+                c
+            }
+        };
+        [component(0), component(1), component(2)]
+    }
+
+    fn apply3<T: Clone + Neg<Output = T>>(&self, v: &[T; 3]) -> [T; 3] {
+        self.apply(v, Neg::neg)
+    }
+
+    fn apply4<T: Clone + Neg<Output = T>>(&self, v: &[T; 4]) -> [T; 4] {
+        let [x, y, z] = self.apply3(&[v[0].clone(), v[1].clone(), v[2].clone()]);
+        [x, y, z, v[3].clone()]
+    }
+}
+
+fn negate3<T: Neg<Output = T>>([x, y, z]: [T; 3]) -> [T; 3] {
+    [-x, -y, -z]
+}
+
+fn negate4<T: Neg<Output = T>>([x, y, z, w]: [T; 4]) -> [T; 4] {
+    [-x, -y, -z, -w]
+}
+
+/// Value that can be converted between coordinate systems.
+///
+/// The conversion only swaps and negates components, so it is exact.
+pub trait Reorient {
+    /// Converts the value from the `F` coordinate system into `To`.
+    fn reorient<F: CoordinateSystem, To: CoordinateSystem>(self) -> Self;
+}
+
+macro_rules! reorient_vec {
+    ($name:ident) => {
+        impl<T: Clone + Neg<Output = T>> Reorient for $name<T> {
+            fn reorient<F: CoordinateSystem, To: CoordinateSystem>(self) -> Self {
+                let swizzle = Swizzle::new::<F, To>();
+                $name::from(swizzle.apply3(self.as_ref()))
+            }
+        }
+    };
+}
+
+reorient_vec!(Vector3);
+reorient_vec!(Point3);
+
+impl<T: Clone + Neg<Output = T>> Reorient for Quaternion<T> {
+    fn reorient<F: CoordinateSystem, To: CoordinateSystem>(self) -> Self {
+        let swizzle = Swizzle::new::<F, To>();
+        // The vector part is an axial vector, so it also flips with the handedness.
+        let v = swizzle.apply3(self.v.as_ref());
+        Quaternion {
+            v: Vector3::from(if swizzle.mirror { negate3(v) } else { v }),
+            s: self.s,
+        }
+    }
+}
+
+// Both row-major and column-major matrices are conjugated by the swizzle
+// the same way, since the swizzle matrix is orthogonal.
+macro_rules! reorient_matrix {
+    ($name:ident : 3) => {
+        impl<T: Clone + Neg<Output = T>> Reorient for $name<T> {
+            fn reorient<F: CoordinateSystem, To: CoordinateSystem>(self) -> Self {
+                let swizzle = Swizzle::new::<F, To>();
+                let m: &[[T; 3]; 3] = self.as_ref();
+                let rows = swizzle.apply(m, negate3);
+                let [x, y, z] = rows;
+                $name::from([swizzle.apply3(&x), swizzle.apply3(&y), swizzle.apply3(&z)])
+            }
+        }
+    };
+    ($name:ident : 4) => {
+        impl<T: Clone + Neg<Output = T>> Reorient for $name<T> {
+            fn reorient<F: CoordinateSystem, To: CoordinateSystem>(self) -> Self {
+                let swizzle = Swizzle::new::<F, To>();
+                let m: &[[T; 4]; 4] = self.as_ref();
+                let rows = swizzle.apply(&[m[0].clone(), m[1].clone(), m[2].clone()], negate4);
+                let [x, y, z] = rows;
+                $name::from([
+                    swizzle.apply4(&x),
+                    swizzle.apply4(&y),
+                    swizzle.apply4(&z),
+                    swizzle.apply4(&m[3]),
+                ])
+            }
+        }
+    };
+}
+
+reorient_matrix!(RowMatrix3: 3);
+reorient_matrix!(ColumnMatrix3: 3);
+reorient_matrix!(RowMatrix4: 4);
+reorient_matrix!(ColumnMatrix4: 4);
+
+impl<V: Reorient, S: CoordinateSystem> Tagged<V, S> {
+    /// Converts the value into another coordinate system.
+    pub fn convert<To: CoordinateSystem>(self) -> Tagged<V, To> {
+        Tagged::new(self.value.reorient::<S, To>())
+    }
+}
@@ -0,0 +1,288 @@
+use crate::vector::{Point2, Point3, Size2, Vector2, Vector3, Vector4};
+use crate::{AsMint, AsMintMut, IntoMint};
+#[cfg(feature = "num-traits")]
+use num_traits::Num;
+
+macro_rules! shape {
+    ($(#[$attr:meta])* $name:ident { $( $(#[$field_attr:meta])* $field:ident : $vec:ident ),* } = ($inner:expr, $outer:expr)) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
+        #[repr(C)]
+        pub struct $name<T> {
+            $(
+                $(#[$field_attr])*
+                pub $field: $vec<T>,
+            )*
+        }
+
+        fixed_conversions!($name<> [$($field),*] = [[T; $inner]; $outer]);
+    };
+}
+
+// Vector and a scalar, packed together as a homogeneous vector.
+macro_rules! packed {
+    ($(#[$attr:meta])* $name:ident {
+        $(#[$vec_attr:meta])* $field:ident : $vec:ident [ $($sub:ident),* ],
+        $(#[$scalar_attr:meta])* $scalar:ident
+    } = $fixed:ty, $homogeneous:ident) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
+        #[repr(C)]
+        pub struct $name<T> {
+            $(#[$vec_attr])*
+            pub $field: $vec<T>,
+            $(#[$scalar_attr])*
+            pub $scalar: T,
+        }
+
+        impl<T> IntoMint for $name<T> {
+            type MintType = $name<T>;
+        }
+
+        unsafe impl<T> AsMint for $name<T> {}
+
+        unsafe impl<T> AsMintMut for $name<T> {}
+
+        impl<T> From<$fixed> for $name<T> {
+            fn from([$($sub,)* $scalar]: $fixed) -> Self {
+                $name {
+                    $field: $vec { $($sub),* },
+                    $scalar,
+                }
+            }
+        }
+
+        impl<T> From<$name<T>> for $fixed {
+            fn from(name: $name<T>) -> $fixed {
+                [$(name.$field.$sub,)* name.$scalar]
+            }
+        }
+
+        impl<T> AsRef<$fixed> for $name<T> {
+            fn as_ref(&self) -> &$fixed { unsafe { ::core::mem::transmute(self) } }
+        }
+
+        impl<T> AsMut<$fixed> for $name<T> {
+            fn as_mut(&mut self) -> &mut $fixed { unsafe { ::core::mem::transmute(self) } }
+        }
+
+        impl<T> From<$homogeneous<T>> for $name<T> {
+            fn from(v: $homogeneous<T>) -> Self {
+                <$fixed>::from(v).into()
+            }
+        }
+
+        impl<T> From<$name<T>> for $homogeneous<T> {
+            fn from(name: $name<T>) -> Self {
+                <$fixed>::from(name).into()
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<T> ::serde::Serialize for $name<T>
+            where T: ::serde::Serialize
+        {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where S: ::serde::Serializer
+            {
+                AsRef::<$fixed>::as_ref(self).serialize(serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de, T> ::serde::Deserialize<'de> for $name<T>
+            where T: ::serde::Deserialize<'de>
+        {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where D: ::serde::Deserializer<'de>
+            {
+                <$fixed>::deserialize(deserializer).map($name::<T>::from)
+            }
+        }
+    };
+}
+
+macro_rules! center_extent {
+    ($name:ident : $point:ident $vec:ident [ $($field:ident),* ]) => {
+        #[cfg(feature = "num-traits")]
+        impl<T: Num + Copy> $name<T> {
+            /// Creates a box from its center and half of its extent along each axis.
+            pub fn from_center_half_extent(center: $point<T>, half_extent: $vec<T>) -> Self {
+                $name {
+                    min: $point {
+                        $(
+                            $field: center.$field - half_extent.$field,
+                        )*
+                    },
+                    max: $point {
+                        $(
+                            $field: center.$field + half_extent.$field,
+                        )*
+                    },
+                }
+            }
+
+            /// Returns the center of the box and half of its extent along each axis.
+            pub fn center_half_extent(&self) -> ($point<T>, $vec<T>) {
+                let two = T::one() + T::one();
+                let center = $point {
+                    $(
+                        $field: (self.min.$field + self.max.$field) / two,
+                    )*
+                };
+                let half_extent = $vec {
+                    $(
+                        $field: (self.max.$field - self.min.$field) / two,
+                    )*
+                };
+                (center, half_extent)
+            }
+        }
+    };
+}
+
+shape!(
+    /// Axis-aligned bounding box in 2D space.
+    Aabb2 {
+        /// Corner with the smallest coordinates.
+        min: Point2,
+        /// Corner with the largest coordinates.
+        max: Point2
+    } = (2, 2)
+);
+center_extent!( Aabb2 : Point2 Vector2 [x, y] );
+
+shape!(
+    /// Axis-aligned bounding box in 3D space.
+    Aabb3 {
+        /// Corner with the smallest coordinates.
+        min: Point3,
+        /// Corner with the largest coordinates.
+        max: Point3
+    } = (3, 2)
+);
+center_extent!( Aabb3 : Point3 Vector3 [x, y, z] );
+
+shape!(
+    /// Rectangle in 2D space.
+    Rect {
+        /// Corner with the smallest coordinates.
+        origin: Point2,
+        /// Extent of the rectangle.
+        size: Size2
+    } = (2, 2)
+);
+
+shape!(
+    /// Half-line in 2D space.
+    Ray2 {
+        /// Starting point.
+        origin: Point2,
+        /// Direction of the ray, not necessarily normalized.
+        direction: Vector2
+    } = (2, 2)
+);
+
+shape!(
+    /// Half-line in 3D space.
+    Ray3 {
+        /// Starting point.
+        origin: Point3,
+        /// Direction of the ray, not necessarily normalized.
+        direction: Vector3
+    } = (3, 2)
+);
+
+shape!(
+    /// Line segment in 2D space.
+    Segment2 {
+        /// First end point.
+        start: Point2,
+        /// Second end point.
+        end: Point2
+    } = (2, 2)
+);
+
+shape!(
+    /// Line segment in 3D space.
+    Segment3 {
+        /// First end point.
+        start: Point3,
+        /// Second end point.
+        end: Point3
+    } = (3, 2)
+);
+
+packed!(
+    /// Line in 2D space, as the set of points satisfying `ax + by + c = 0`.
+    ///
+    /// The components `[a, b, c]` match `[normal.x, normal.y, distance]`,
+    /// so for a normalized `normal` the signed distance from the origin
+    /// to the line along the normal is `-distance`.
+    Line2 {
+        /// Normal vector, pointing towards the positive half-plane.
+        normal: Vector2 [x, y],
+        /// Constant term of the equation.
+        distance
+    } = [T; 3], Vector3
+);
+
+packed!(
+    /// Plane in 3D space, as the set of points satisfying `ax + by + cz + d = 0`.
+    ///
+    /// The components `[a, b, c, d]` match `[normal.x, normal.y, normal.z, distance]`,
+    /// so for a normalized `normal` the signed distance from the origin
+    /// to the plane along the normal is `-distance`.
+    Plane {
+        /// Normal vector, pointing towards the positive half-space.
+        normal: Vector3 [x, y, z],
+        /// Constant term of the equation.
+        distance
+    } = [T; 4], Vector4
+);
+
+packed!(
+    /// Sphere in 3D space, such as a bounding sphere.
+    ///
+    /// The components match `[center.x, center.y, center.z, radius]`.
+    Sphere {
+        /// Center point.
+        center: Point3 [x, y, z],
+        /// Radius of the sphere.
+        radius
+    } = [T; 4], Vector4
+);
+
+shape!(
+    /// Triangle in 3D space.
+    Triangle3 {
+        /// First vertex.
+        a: Point3,
+        /// Second vertex.
+        b: Point3,
+        /// Third vertex.
+        c: Point3
+    } = (3, 3)
+);
+
+shape!(
+    /// View frustum, bounded by six planes.
+    ///
+    /// The normals of the planes point towards the inside of the frustum,
+    /// so a point is inside when it is in the positive half-space of every plane.
+    /// The planes are stored in the order: left, right, bottom, top, near, far.
+    Frustum {
+        /// Left plane.
+        left: Plane,
+        /// Right plane.
+        right: Plane,
+        /// Bottom plane.
+        bottom: Plane,
+        /// Top plane.
+        top: Plane,
+        /// Near plane.
+        near: Plane,
+        /// Far plane.
+        far: Plane
+    } = (4, 6)
+);
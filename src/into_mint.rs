@@ -1,3 +1,5 @@
+use crate::vector::{Vector2, Vector3, Vector4};
+
 /// Defines which mint type a given type is associated with. This trait enables
 /// converting a type into its mint equivalent without having to name the mint
 /// type.
@@ -11,3 +13,92 @@ pub trait IntoMint: Into<Self::MintType> {
     /// The mint type that this type is associated with.
     type MintType;
 }
+
+/// Types that can be constructed from their mint type.
+///
+/// Implemented for every `IntoMint` type with the reverse `From` conversion,
+/// which includes all mint types.
+pub trait FromMint: IntoMint + From<<Self as IntoMint>::MintType> {
+    /// Constructs the value from its mint equivalent.
+    fn from_mint(mint: Self::MintType) -> Self {
+        Self::from(mint)
+    }
+}
+
+impl<T: IntoMint + From<T::MintType>> FromMint for T {}
+
+/// Method-call conversions of `IntoMint` types.
+pub trait MintExt: IntoMint {
+    /// Converts the value into its mint equivalent.
+    fn into_mint(self) -> Self::MintType {
+        self.into()
+    }
+}
+
+impl<T: IntoMint> MintExt for T {}
+
+/// Converts between two types with the same mint equivalent,
+/// such as vectors of different math libraries.
+pub fn convert<A, B>(a: A) -> B
+where
+    A: IntoMint,
+    B: FromMint<MintType = A::MintType>,
+{
+    B::from_mint(a.into())
+}
+
+/// Borrowing conversion of a type into its mint equivalent.
+///
+/// # Safety
+///
+/// The type must have the same layout as `MintType`: the same size, alignment,
+/// and position of every field, such that any valid value of the type is also
+/// a valid value of `MintType`.
+///
+/// All mint types implement `AsMint` reflexively.
+pub unsafe trait AsMint: IntoMint {
+    /// Borrows the value as its mint equivalent.
+    fn as_mint(&self) -> &Self::MintType {
+        unsafe { &*(self as *const Self as *const Self::MintType) }
+    }
+}
+
+/// Mutably borrowing conversion of a type into its mint equivalent.
+///
+/// # Safety
+///
+/// In addition to the requirements of `AsMint`, any valid value of `MintType`
+/// must also be a valid value of the type.
+///
+/// All mint types implement `AsMintMut` reflexively.
+pub unsafe trait AsMintMut: AsMint {
+    /// Mutably borrows the value as its mint equivalent.
+    fn as_mint_mut(&mut self) -> &mut Self::MintType {
+        unsafe { &mut *(self as *mut Self as *mut Self::MintType) }
+    }
+}
+
+/// Types laid out as the column `V` of a mint matrix, checked by the `as_ref` form
+/// of the matrix conversion macros.
+#[doc(hidden)]
+pub unsafe trait __MintColumn<V> {}
+
+unsafe impl<T> __MintColumn<Vector2<T>> for [T; 2] {}
+unsafe impl<T> __MintColumn<Vector3<T>> for [T; 3] {}
+unsafe impl<T> __MintColumn<Vector4<T>> for [T; 4] {}
+unsafe impl<A: AsMintMut> __MintColumn<A::MintType> for A {}
+
+#[doc(hidden)]
+pub fn __mint_column<C: __MintColumn<V>, V>(_: &C) {}
+
+/// Borrows a slice of values as a slice of their mint equivalents.
+pub fn as_mint_slice<A: AsMint>(values: &[A]) -> &[A::MintType] {
+    unsafe { ::core::slice::from_raw_parts(values.as_ptr() as *const A::MintType, values.len()) }
+}
+
+/// Mutably borrows a slice of values as a slice of their mint equivalents.
+pub fn as_mint_slice_mut<A: AsMintMut>(values: &mut [A]) -> &mut [A::MintType] {
+    unsafe {
+        ::core::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut A::MintType, values.len())
+    }
+}
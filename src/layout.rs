@@ -0,0 +1,130 @@
+use crate::matrix::*;
+use crate::rotation::{EulerAngles, Quaternion};
+use crate::vector::*;
+
+/// Kind of a scalar component.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[allow(missing_docs)]
+pub enum ScalarKind {
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    I64,
+    U64,
+    F32,
+    F64,
+}
+
+/// Primitive type that can be a component of a mint type with known layout.
+///
+/// # Safety
+///
+/// Every bit pattern of the type must be a valid value, and it must have no padding,
+/// since buffers of bytes may be reinterpreted as such.
+pub unsafe trait Scalar {
+    /// Kind of the scalar.
+    const KIND: ScalarKind;
+}
+
+macro_rules! scalar {
+    ($($ty:ident = $kind:ident),*) => {
+        $(
+            unsafe impl Scalar for $ty {
+                const KIND: ScalarKind = ScalarKind::$kind;
+            }
+        )*
+    };
+}
+
+scalar!(
+    i8 = I8,
+    u8 = U8,
+    i16 = I16,
+    u16 = U16,
+    i32 = I32,
+    u32 = U32,
+    i64 = I64,
+    u64 = U64,
+    f32 = F32,
+    f64 = F64
+);
+
+/// Order in which the components of a mint type are stored.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Major {
+    /// Components of each row are contiguous.
+    Row,
+    /// Components of each column are contiguous. Vectors are single columns.
+    Column,
+}
+
+/// Memory layout of a mint type, for deriving vertex formats
+/// or reflecting shader uniforms generically.
+///
+/// # Safety
+///
+/// The type must be laid out exactly as `[Self::Scalar; Self::COMPONENTS]`,
+/// with the same size and alignment, since slices of it may be reinterpreted as such.
+pub unsafe trait MintLayout {
+    /// Type of the components.
+    type Scalar: Scalar;
+    /// Number of rows.
+    const ROWS: usize;
+    /// Number of columns.
+    const COLUMNS: usize;
+    /// Total number of components.
+    const COMPONENTS: usize = Self::ROWS * Self::COLUMNS;
+    /// Storage order of the components.
+    const MAJOR: Major;
+    /// Kind of the components.
+    const SCALAR_KIND: ScalarKind = <Self::Scalar as Scalar>::KIND;
+    /// Size of a component in bytes.
+    const SCALAR_SIZE: usize = ::core::mem::size_of::<Self::Scalar>();
+}
+
+macro_rules! layout {
+    ($major:ident ($rows:expr, $columns:expr) => $($name:ident),*) => {
+        $(
+            unsafe impl<T: Scalar> MintLayout for $name<T> {
+                type Scalar = T;
+                const ROWS: usize = $rows;
+                const COLUMNS: usize = $columns;
+                const MAJOR: Major = Major::$major;
+            }
+        )*
+    };
+}
+
+layout!(Column (2, 1) => Vector2, Point2, Size2, Offset2);
+layout!(Column (3, 1) => Vector3, Point3, Size3);
+layout!(Column (4, 1) => Vector4, Quaternion);
+
+layout!(Row (2, 2) => RowMatrix2);
+layout!(Row (2, 3) => RowMatrix2x3);
+layout!(Row (2, 4) => RowMatrix2x4);
+layout!(Row (3, 2) => RowMatrix3x2);
+layout!(Row (3, 3) => RowMatrix3);
+layout!(Row (3, 4) => RowMatrix3x4);
+layout!(Row (4, 2) => RowMatrix4x2);
+layout!(Row (4, 3) => RowMatrix4x3);
+layout!(Row (4, 4) => RowMatrix4);
+
+layout!(Column (2, 2) => ColumnMatrix2);
+layout!(Column (2, 3) => ColumnMatrix2x3);
+layout!(Column (2, 4) => ColumnMatrix2x4);
+layout!(Column (3, 2) => ColumnMatrix3x2);
+layout!(Column (3, 3) => ColumnMatrix3);
+layout!(Column (3, 4) => ColumnMatrix3x4);
+layout!(Column (4, 2) => ColumnMatrix4x2);
+layout!(Column (4, 3) => ColumnMatrix4x3);
+layout!(Column (4, 4) => ColumnMatrix4);
+
+unsafe impl<T: Scalar, B> MintLayout for EulerAngles<T, B> {
+    type Scalar = T;
+    const ROWS: usize = 3;
+    const COLUMNS: usize = 1;
+    const MAJOR: Major = Major::Column;
+}
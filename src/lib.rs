@@ -15,12 +15,59 @@ Designed to serve as an interoperability standard between libraries.
     clippy::all
 )]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[macro_use]
+mod macros;
+
+mod angle;
+mod camera;
+mod color;
+mod container;
+mod coordinate;
+mod geometry;
 mod into_mint;
+mod layout;
 mod matrix;
+mod padded;
+mod projection;
 mod rotation;
+#[cfg(feature = "shader")]
+mod shader;
+mod slice;
+#[cfg(feature = "alloc")]
+mod soa;
+mod strided;
+mod tagged;
+mod text;
+mod unit;
+mod value;
 mod vector;
 
+#[cfg(feature = "derive")]
+pub use mint_derive::IntoMint;
+
+pub use angle::*;
+pub use camera::*;
+pub use color::*;
+pub use container::*;
+pub use coordinate::*;
+pub use geometry::*;
 pub use into_mint::*;
+pub use layout::*;
 pub use matrix::*;
+pub use padded::*;
+pub use projection::*;
 pub use rotation::*;
+#[cfg(feature = "shader")]
+pub use shader::*;
+pub use slice::*;
+#[cfg(feature = "alloc")]
+pub use soa::*;
+pub use strided::*;
+pub use tagged::*;
+pub use text::*;
+pub use unit::*;
+pub use value::*;
 pub use vector::*;
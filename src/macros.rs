@@ -0,0 +1,365 @@
+//! Macros for implementing the conversions of mint types and of downstream types.
+
+// Implements `IntoMint`, `AsMint`, and the conversions both ways between a mint type
+// and the array of its fields, converting each field with `From`.
+// Unless `@from` is given, the type is also borrowed as the array and serialized as it,
+// so it must be laid out as the array, possibly followed by padding.
+macro_rules! fixed_conversions {
+    (@from $name:ident < $($param:ident),* > [ $($field:ident),* ] $(+ $marker:ident)? = $fixed:ty) => {
+        impl<T, $($param),*> $crate::IntoMint for $name<T, $($param),*> {
+            type MintType = $name<T, $($param),*>;
+        }
+
+        unsafe impl<T, $($param),*> $crate::AsMint for $name<T, $($param),*> {}
+
+        unsafe impl<T, $($param),*> $crate::AsMintMut for $name<T, $($param),*> {}
+
+        impl<T, $($param),*> From<$fixed> for $name<T, $($param),*> {
+            fn from([$($field),*]: $fixed) -> Self {
+                $name {
+                    $(
+                        $field: From::from($field),
+                    )*
+                    $( $marker: ::core::marker::PhantomData, )?
+                }
+            }
+        }
+
+        impl<T, $($param),*> From<$name<T, $($param),*>> for $fixed {
+            fn from(name: $name<T, $($param),*>) -> $fixed {
+                [$( name.$field.into() ),*]
+            }
+        }
+    };
+    ($name:ident < $($param:ident),* > [ $($field:ident),* ] $(+ $marker:ident)? = $fixed:ty) => {
+        fixed_conversions!(@from $name<$($param),*> [$($field),*] $(+ $marker)? = $fixed);
+
+        impl<T, $($param),*> AsRef<$fixed> for $name<T, $($param),*> {
+            fn as_ref(&self) -> &$fixed { unsafe { &*(self as *const Self as *const $fixed) } }
+        }
+
+        impl<T, $($param),*> AsMut<$fixed> for $name<T, $($param),*> {
+            fn as_mut(&mut self) -> &mut $fixed { unsafe { &mut *(self as *mut Self as *mut $fixed) } }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<T, $($param),*> ::serde::Serialize for $name<T, $($param),*>
+            where T: ::serde::Serialize
+        {
+            fn serialize<Z>(&self, serializer: Z) -> Result<Z::Ok, Z::Error>
+                where Z: ::serde::Serializer
+            {
+                AsRef::<$fixed>::as_ref(self).serialize(serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de, T, $($param),*> ::serde::Deserialize<'de> for $name<T, $($param),*>
+            where T: ::serde::Deserialize<'de>
+        {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where D: ::serde::Deserializer<'de>
+            {
+                <$fixed>::deserialize(deserializer).map(Self::from)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_mint_conversions {
+    ([$($gen:tt)*] $ty:ty => $mint:ty { $($field:tt => $mfield:ident),* }) => {
+        impl<$($gen)*> $crate::IntoMint for $ty {
+            type MintType = $mint;
+        }
+
+        impl<$($gen)*> ::core::convert::From<$ty> for $mint {
+            #[allow(clippy::useless_conversion)]
+            fn from(value: $ty) -> Self {
+                Self {
+                    $( $mfield: value.$field.into(), )*
+                }
+            }
+        }
+
+        impl<$($gen)*> ::core::convert::From<$mint> for $ty {
+            #[allow(clippy::useless_conversion)]
+            fn from(mint: $mint) -> Self {
+                Self {
+                    $( $field: mint.$mfield.into(), )*
+                }
+            }
+        }
+    };
+    (as_ref $ty:ty => $mint:ty { $($field:tt => $mfield:ident),* }) => {
+        const _: () = assert!(
+            ::core::mem::size_of::<$ty>() == ::core::mem::size_of::<$mint>()
+                && ::core::mem::align_of::<$ty>() == ::core::mem::align_of::<$mint>(),
+            "The type doesn't have the size and the alignment of the mint type.",
+        );
+        $(
+            const _: () = assert!(
+                ::core::mem::offset_of!($ty, $field) == ::core::mem::offset_of!($mint, $mfield),
+                concat!("The field `", stringify!($field), "` is not at the place of `", stringify!($mfield), "`."),
+            );
+        )*
+
+        unsafe impl $crate::AsMint for $ty {}
+
+        unsafe impl $crate::AsMintMut for $ty {}
+
+        impl ::core::convert::AsRef<$mint> for $ty {
+            fn as_ref(&self) -> &$mint {
+                $crate::AsMint::as_mint(self)
+            }
+        }
+
+        impl ::core::convert::AsMut<$mint> for $ty {
+            fn as_mut(&mut self) -> &mut $mint {
+                $crate::AsMintMut::as_mint_mut(self)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_mint_vector_conversions {
+    ($mint:ident [$($mfield:ident),*] < $($gen:ident),* > $ty:ty : $scalar:ty, $($field:tt),*) => {
+        $crate::__impl_mint_conversions!(
+            [$($gen),*] $ty => $crate::$mint<$scalar> { $($field => $mfield),* }
+        );
+    };
+    ($mint:ident [$($mfield:ident),*] $ty:ty : $scalar:ty, $($field:tt),* ; as_ref) => {
+        $crate::__impl_mint_vector_conversions!($mint [$($mfield),*] $ty : $scalar, $($field),*);
+        // Every field must be a scalar, for any value of the mint type to be valid.
+        const _: fn(&$ty) = |value| {
+            $( let _: &$scalar = &value.$field; )*
+        };
+        $crate::__impl_mint_conversions!(
+            as_ref $ty => $crate::$mint<$scalar> { $($field => $mfield),* }
+        );
+    };
+    ($mint:ident [$($mfield:ident),*] $ty:ty : $scalar:ty, $($field:tt),*) => {
+        $crate::__impl_mint_conversions!(
+            [] $ty => $crate::$mint<$scalar> { $($field => $mfield),* }
+        );
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_mint_matrix_conversions {
+    ([$($mfield:ident),*] $vec:ident $rows:ident $cols:ident < $($gen:ident),* > $ty:ty : $scalar:ty, column_major, $($field:tt),*) => {
+        $crate::__impl_mint_conversions!(
+            [$($gen),*] $ty => $crate::$cols<$scalar> { $($field => $mfield),* }
+        );
+    };
+    ([$($mfield:ident),*] $vec:ident $rows:ident $cols:ident < $($gen:ident),* > $ty:ty : $scalar:ty, row_major, $($field:tt),*) => {
+        $crate::__impl_mint_conversions!(
+            [$($gen),*] $ty => $crate::$rows<$scalar> { $($field => $mfield),* }
+        );
+    };
+    ([$($mfield:ident),*] $vec:ident $rows:ident $cols:ident $ty:ty : $scalar:ty, $major:ident, $($field:tt),* ; as_ref) => {
+        $crate::__impl_mint_matrix_conversions!(
+            [$($mfield),*] $vec $rows $cols $ty : $scalar, $major, $($field),*
+        );
+        // Every field must be laid out as a column, or as a row in row-major order.
+        const _: fn(&$ty) = |value| {
+            $( $crate::__mint_column::<_, $crate::$vec<$scalar>>(&value.$field); )*
+        };
+        $crate::__impl_mint_matrix_conversions!(
+            @as_ref [$($mfield),*] $rows $cols $ty : $scalar, $major, $($field),*
+        );
+    };
+    (@as_ref [$($mfield:ident),*] $rows:ident $cols:ident $ty:ty : $scalar:ty, column_major, $($field:tt),*) => {
+        $crate::__impl_mint_conversions!(
+            as_ref $ty => $crate::$cols<$scalar> { $($field => $mfield),* }
+        );
+    };
+    (@as_ref [$($mfield:ident),*] $rows:ident $cols:ident $ty:ty : $scalar:ty, row_major, $($field:tt),*) => {
+        $crate::__impl_mint_conversions!(
+            as_ref $ty => $crate::$rows<$scalar> { $($field => $mfield),* }
+        );
+    };
+    ([$($mfield:ident),*] $vec:ident $rows:ident $cols:ident $ty:ty : $scalar:ty, column_major, $($field:tt),*) => {
+        $crate::__impl_mint_conversions!(
+            [] $ty => $crate::$cols<$scalar> { $($field => $mfield),* }
+        );
+    };
+    ([$($mfield:ident),*] $vec:ident $rows:ident $cols:ident $ty:ty : $scalar:ty, row_major, $($field:tt),*) => {
+        $crate::__impl_mint_conversions!(
+            [] $ty => $crate::$rows<$scalar> { $($field => $mfield),* }
+        );
+    };
+}
+
+// Defines a struct of scalar fields with the conversions of `fixed_conversions!`.
+macro_rules! vec {
+    ($(#[$attr:meta])* $name:ident [ $( $(#[$field_attr:meta])* $field:ident ),* ] = $fixed:ty) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
+        #[repr(C)]
+        #[allow(missing_docs)] //TODO: actually have docs
+        pub struct $name<T> {
+            $(
+                $(#[$field_attr])*
+                pub $field: T,
+            )*
+        }
+
+        fixed_conversions!($name<> [$($field),*] = $fixed);
+
+        impl<T: Clone> $name<T> {
+            #[allow(missing_docs)]
+            pub fn from_slice(slice: &[T]) -> Self {
+                let mut iter = slice.iter();
+                $name {
+                    $(
+                        $field: iter
+                            .next()
+                            .expect(&concat!("Missing ", stringify!($field), "-axis in slice."))
+                            .clone()
+                    ),*
+                }
+            }
+        }
+    }
+}
+
+/// Implements `IntoMint` and the conversions both ways between a type and `Vector2`.
+///
+/// Takes the type, its scalar type, and the fields in the order of `x` and `y`.
+/// Generic types list their parameters first:
+///
+/// ```
+/// struct Uv { u: f32, v: f32 }
+/// mint::impl_vector2_conversions!(Uv: f32, u, v);
+///
+/// struct Size<T> { width: T, height: T }
+/// mint::impl_vector2_conversions!(<T> Size<T>: T, width, height);
+/// ```
+///
+/// Adding `; as_ref` to a non-generic type also implements `AsMint`, `AsMintMut`,
+/// `AsRef`, and `AsMut` to the mint type. The type must be `#[repr(C)]` with its fields
+/// of the scalar type, declared in the order they are given. Its size, alignment,
+/// and field offsets are checked at compile time, which needs Rust 1.77 or newer:
+///
+/// ```
+/// #[repr(C)]
+/// struct Uv { u: f32, v: f32 }
+/// mint::impl_vector2_conversions!(Uv: f32, u, v; as_ref);
+///
+/// let mut uv = Uv { u: 0.5, v: 0.25 };
+/// AsMut::<mint::Vector2<f32>>::as_mut(&mut uv).y = 1.0;
+/// assert_eq!(uv.v, 1.0);
+/// ```
+///
+/// Fields given in another order than they are declared fail to compile:
+///
+/// ```compile_fail
+/// #[repr(C)]
+/// struct Vu { v: f32, u: f32 }
+/// mint::impl_vector2_conversions!(Vu: f32, u, v; as_ref);
+/// ```
+#[macro_export]
+macro_rules! impl_vector2_conversions {
+    ($($tt:tt)*) => { $crate::__impl_mint_vector_conversions!(Vector2 [x, y] $($tt)*); };
+}
+
+/// Implements `IntoMint` and the conversions both ways between a type and `Vector3`.
+///
+/// Takes the type, its scalar type, and the fields in the order of `x`, `y`, and `z`.
+///
+/// ```
+/// struct MyVec3 { x: f32, y: f32, z: f32 }
+/// mint::impl_vector3_conversions!(MyVec3: f32, x, y, z);
+///
+/// let v: mint::Vector3<f32> = MyVec3 { x: 1.0, y: 2.0, z: 3.0 }.into();
+/// assert_eq!(v.z, 3.0);
+/// ```
+///
+/// See `impl_vector2_conversions` for the other forms.
+#[macro_export]
+macro_rules! impl_vector3_conversions {
+    ($($tt:tt)*) => { $crate::__impl_mint_vector_conversions!(Vector3 [x, y, z] $($tt)*); };
+}
+
+/// Implements `IntoMint` and the conversions both ways between a type and `Vector4`.
+///
+/// Takes the type, its scalar type, and the fields in the order of `x`, `y`, `z`, and `w`.
+/// See `impl_vector2_conversions` for the other forms.
+#[macro_export]
+macro_rules! impl_vector4_conversions {
+    ($($tt:tt)*) => { $crate::__impl_mint_vector_conversions!(Vector4 [x, y, z, w] $($tt)*); };
+}
+
+/// Implements `IntoMint` and the conversions both ways between a type and `Point2`.
+///
+/// Takes the type, its scalar type, and the fields in the order of `x` and `y`.
+/// See `impl_vector2_conversions` for the other forms.
+#[macro_export]
+macro_rules! impl_point2_conversions {
+    ($($tt:tt)*) => { $crate::__impl_mint_vector_conversions!(Point2 [x, y] $($tt)*); };
+}
+
+/// Implements `IntoMint` and the conversions both ways between a type and `Point3`.
+///
+/// Takes the type, its scalar type, and the fields in the order of `x`, `y`, and `z`.
+/// See `impl_vector2_conversions` for the other forms.
+#[macro_export]
+macro_rules! impl_point3_conversions {
+    ($($tt:tt)*) => { $crate::__impl_mint_vector_conversions!(Point3 [x, y, z] $($tt)*); };
+}
+
+/// Implements `IntoMint` and the conversions both ways between a type and
+/// `ColumnMatrix2` or `RowMatrix2`.
+///
+/// Takes the type, its scalar type, `column_major` or `row_major`, and the fields
+/// holding the columns or the rows, in order. The fields are converted with `Into`,
+/// so they may be arrays or types with `Vector2` conversions.
+/// See `impl_matrix4_conversions` for an example.
+#[macro_export]
+macro_rules! impl_matrix2_conversions {
+    ($($tt:tt)*) => {
+        $crate::__impl_mint_matrix_conversions!([x, y] Vector2 RowMatrix2 ColumnMatrix2 $($tt)*);
+    };
+}
+
+/// Implements `IntoMint` and the conversions both ways between a type and
+/// `ColumnMatrix3` or `RowMatrix3`.
+///
+/// Takes the type, its scalar type, `column_major` or `row_major`, and the fields
+/// holding the columns or the rows, in order. The fields are converted with `Into`,
+/// so they may be arrays or types with `Vector3` conversions.
+/// See `impl_matrix4_conversions` for an example.
+#[macro_export]
+macro_rules! impl_matrix3_conversions {
+    ($($tt:tt)*) => {
+        $crate::__impl_mint_matrix_conversions!([x, y, z] Vector3 RowMatrix3 ColumnMatrix3 $($tt)*);
+    };
+}
+
+/// Implements `IntoMint` and the conversions both ways between a type and
+/// `ColumnMatrix4` or `RowMatrix4`.
+///
+/// Takes the type, its scalar type, `column_major` or `row_major`, and the fields
+/// holding the columns or the rows, in order. The fields are converted with `Into`,
+/// so they may be arrays or types with `Vector4` conversions.
+///
+/// ```
+/// struct Transform { x_axis: [f32; 4], y_axis: [f32; 4], z_axis: [f32; 4], w_axis: [f32; 4] }
+///
+/// mint::impl_matrix4_conversions!(Transform: f32, column_major, x_axis, y_axis, z_axis, w_axis);
+/// ```
+///
+/// Adding `; as_ref` to a non-generic type also implements `AsMint`, `AsMintMut`,
+/// `AsRef`, and `AsMut`, as for `impl_vector2_conversions`. The fields must then be
+/// arrays, or types borrowed as `Vector4` with `AsMintMut`.
+#[macro_export]
+macro_rules! impl_matrix4_conversions {
+    ($($tt:tt)*) => {
+        $crate::__impl_mint_matrix_conversions!([x, y, z, w] Vector4 RowMatrix4 ColumnMatrix4 $($tt)*);
+    };
+}
@@ -1,5 +1,6 @@
 use crate::vector::{Vector2, Vector3, Vector4};
-use crate::IntoMint;
+#[cfg(feature = "num-traits")]
+use num_traits::{One, Zero};
 
 macro_rules! matrix {
     ($name:ident : $vec:ident[ $($field:ident[$($sub:ident),*] = $index:expr),* ] = ($inner:expr, $outer:expr)) => {
@@ -10,33 +11,7 @@ macro_rules! matrix {
             $( pub $field : $vec<T>, )*
         }
 
-        impl<T> IntoMint for $name<T> {
-            type MintType = $name<T>;
-        }
-
-        impl<T> From<[[T; $inner]; $outer]> for $name<T> {
-            fn from([$($field),*]: [[T; $inner]; $outer]) -> Self {
-                $name {
-                    $(
-                        $field: From::from($field),
-                    )*
-                }
-            }
-        }
-
-        impl<T> From<$name<T>> for [[T; $inner]; $outer] {
-            fn from(name: $name<T>) -> [[T; $inner]; $outer] {
-                [$( name.$field.into() ),*]
-            }
-        }
-
-        impl<T> AsRef<[[T; $inner]; $outer]> for $name<T> {
-            fn as_ref(&self) -> &[[T; $inner]; $outer] { unsafe { ::core::mem::transmute(self) } }
-        }
-
-        impl<T> AsMut<[[T; $inner]; $outer]> for $name<T> {
-            fn as_mut(&mut self) -> &mut [[T; $inner]; $outer] { unsafe { ::core::mem::transmute(self) } }
-        }
+        fixed_conversions!($name<> [$($field),*] = [[T; $inner]; $outer]);
 
         impl<T: Clone> From<[T; $inner * $outer]> for $name<T> {
             fn from(m: [T; $inner * $outer]) -> Self {
@@ -64,28 +39,6 @@ macro_rules! matrix {
         impl<T> AsMut<[T; $inner * $outer]> for $name<T> {
             fn as_mut(&mut self) -> &mut [T; $inner * $outer] { unsafe { ::core::mem::transmute(self) } }
         }
-
-        #[cfg(feature = "serde")]
-        impl<T> ::serde::Serialize for $name<T>
-            where T: ::serde::Serialize
-        {
-            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-                where S: ::serde::Serializer
-            {
-                AsRef::<[[T; $inner]; $outer]>::as_ref(self).serialize(serializer)
-            }
-        }
-
-        #[cfg(feature = "serde")]
-        impl<'de, T> ::serde::Deserialize<'de> for $name<T>
-            where T: ::serde::Deserialize<'de>
-        {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-                where D: ::serde::Deserializer<'de>
-            {
-                <[[T; $inner]; $outer]>::deserialize(deserializer).map($name::<T>::from)
-            }
-        }
     };
 }
 
@@ -172,3 +125,138 @@ turn!( ColumnMatrix4x3 : Vector4[x[x,y,z,w],y[x,y,z,w],z[x,y,z,w]] = RowMatrix4x
 // 4x4 column-major matrix.
 matrix!( ColumnMatrix4 : Vector4[x[x,y,z,w]=0,y[x,y,z,w]=1,z[x,y,z,w]=2,w[x,y,z,w]=3] = (4, 4));
 turn!( ColumnMatrix4 : Vector4[x[x,y,z,w],y[x,y,z,w],z[x,y,z,w],w[x,y,z,w]] = RowMatrix4 );
+
+macro_rules! resize {
+    // Square matrix of one size smaller: every vector gains a zero component,
+    // and a new identity vector is appended.
+    ($small:ident : $svec:ident [ $($field:ident [ $($sub:ident),* ] ),* ] <-> $big:ident : $bvec:ident + $last:ident) => {
+        impl<T> $big<T> {
+            /// Returns the upper-left corner of the matrix.
+            pub fn truncate(self) -> $small<T> {
+                $small {
+                    $(
+                        $field: $svec {
+                            $(
+                                $sub: self.$field.$sub,
+                            )*
+                        },
+                    )*
+                }
+            }
+        }
+
+        #[cfg(feature = "num-traits")]
+        impl<T: Zero + One> $small<T> {
+            /// Embeds the matrix into the upper-left corner of an identity matrix.
+            pub fn extend(self) -> $big<T> {
+                $big {
+                    $(
+                        $field: $bvec {
+                            $(
+                                $sub: self.$field.$sub,
+                            )*
+                            $last: T::zero(),
+                        },
+                    )*
+                    $last: $bvec {
+                        $(
+                            $field: T::zero(),
+                        )*
+                        $last: T::one(),
+                    },
+                }
+            }
+        }
+    };
+}
+
+macro_rules! resize_affine {
+    // Row-major affine matrix: the bottom row of the square matrix is dropped.
+    (row $small:ident <-> $big:ident : $vec:ident [ $($field:ident),* ] + $last:ident) => {
+        impl<T> $big<T> {
+            /// Returns the affine part of the matrix, dropping the bottom row.
+            pub fn truncate_affine(self) -> $small<T> {
+                $small {
+                    $(
+                        $field: self.$field,
+                    )*
+                }
+            }
+        }
+
+        #[cfg(feature = "num-traits")]
+        impl<T: Zero + One> $small<T> {
+            /// Completes the affine matrix with the bottom row of an identity matrix.
+            pub fn extend(self) -> $big<T> {
+                $big {
+                    $(
+                        $field: self.$field,
+                    )*
+                    $last: $vec {
+                        $(
+                            $field: T::zero(),
+                        )*
+                        $last: T::one(),
+                    },
+                }
+            }
+        }
+    };
+    // Column-major affine matrix: every column loses its bottom component.
+    (column $small:ident : $svec:ident [ $($field:ident [ $($sub:ident),* ] ),* ] <-> $big:ident : $bvec:ident + $last:ident) => {
+        impl<T> $big<T> {
+            /// Returns the affine part of the matrix, dropping the bottom row.
+            pub fn truncate_affine(self) -> $small<T> {
+                $small {
+                    $(
+                        $field: $svec {
+                            $(
+                                $sub: self.$field.$sub,
+                            )*
+                        },
+                    )*
+                    $last: $svec {
+                        $(
+                            $field: self.$last.$field,
+                        )*
+                    },
+                }
+            }
+        }
+
+        #[cfg(feature = "num-traits")]
+        impl<T: Zero + One> $small<T> {
+            /// Completes the affine matrix with the bottom row of an identity matrix.
+            pub fn extend(self) -> $big<T> {
+                $big {
+                    $(
+                        $field: $bvec {
+                            $(
+                                $sub: self.$field.$sub,
+                            )*
+                            $last: T::zero(),
+                        },
+                    )*
+                    $last: $bvec {
+                        $(
+                            $field: self.$last.$field,
+                        )*
+                        $last: T::one(),
+                    },
+                }
+            }
+        }
+    };
+}
+
+// 2x2 <-> 3x3 <-> 4x4 square matrices.
+resize!( RowMatrix2 : Vector2[x[x,y],y[x,y]] <-> RowMatrix3 : Vector3 + z );
+resize!( RowMatrix3 : Vector3[x[x,y,z],y[x,y,z],z[x,y,z]] <-> RowMatrix4 : Vector4 + w );
+resize!( ColumnMatrix2 : Vector2[x[x,y],y[x,y]] <-> ColumnMatrix3 : Vector3 + z );
+resize!( ColumnMatrix3 : Vector3[x[x,y,z],y[x,y,z],z[x,y,z]] <-> ColumnMatrix4 : Vector4 + w );
+
+// 2x3 <-> 3x3 and 3x4 <-> 4x4 affine matrices.
+resize_affine!( row RowMatrix2x3 <-> RowMatrix3 : Vector3[x,y] + z );
+resize_affine!( row RowMatrix3x4 <-> RowMatrix4 : Vector4[x,y,z] + w );
+resize_affine!( column ColumnMatrix2x3 : Vector2[x[x,y],y[x,y]] <-> ColumnMatrix3 : Vector3 + z );
+resize_affine!( column ColumnMatrix3x4 : Vector3[x[x,y,z],y[x,y,z],z[x,y,z]] <-> ColumnMatrix4 : Vector4 + w );
@@ -0,0 +1,125 @@
+use crate::matrix::{ColumnMatrix2, ColumnMatrix3};
+use crate::vector::{Vector2, Vector3};
+
+macro_rules! aligned {
+    ($(#[$attr:meta])* $name:ident [ $($field:ident),* ] = $fixed:ty, $vec:ident) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
+        #[repr(C, align(16))]
+        #[allow(missing_docs)]
+        pub struct $name<T> {
+            $( pub $field: T, )*
+        }
+
+        fixed_conversions!($name<> [$($field),*] = $fixed);
+
+        impl<T> From<$vec<T>> for $name<T> {
+            fn from(v: $vec<T>) -> Self {
+                $name {
+                    $(
+                        $field: v.$field,
+                    )*
+                }
+            }
+        }
+
+        impl<T> From<$name<T>> for $vec<T> {
+            fn from(name: $name<T>) -> Self {
+                $vec {
+                    $(
+                        $field: name.$field,
+                    )*
+                }
+            }
+        }
+
+        // The components are laid out as in the unpadded type, followed by the padding.
+        impl<T> AsRef<$vec<T>> for $name<T> {
+            fn as_ref(&self) -> &$vec<T> { unsafe { &*(self as *const Self as *const $vec<T>) } }
+        }
+
+        impl<T> AsMut<$vec<T>> for $name<T> {
+            fn as_mut(&mut self) -> &mut $vec<T> { unsafe { &mut *(self as *mut Self as *mut $vec<T>) } }
+        }
+    };
+}
+
+macro_rules! padded_matrix {
+    ($(#[$attr:meta])* $name:ident : $vec:ident [ $($field:ident),* ] = ($inner:expr, $outer:expr), $unpadded:ident) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
+        #[repr(C)]
+        #[allow(missing_docs)]
+        pub struct $name<T> {
+            $( pub $field: $vec<T>, )*
+        }
+
+        // The columns are padded, so the matrix can't be borrowed as the nested array.
+        fixed_conversions!(@from $name<> [$($field),*] = [[T; $inner]; $outer]);
+
+        impl<T> From<$unpadded<T>> for $name<T> {
+            fn from(m: $unpadded<T>) -> Self {
+                $name {
+                    $(
+                        $field: From::from(m.$field),
+                    )*
+                }
+            }
+        }
+
+        impl<T> From<$name<T>> for $unpadded<T> {
+            fn from(m: $name<T>) -> Self {
+                $unpadded {
+                    $(
+                        $field: From::from(m.$field),
+                    )*
+                }
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<T> ::serde::Serialize for $name<T>
+            where T: ::serde::Serialize
+        {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where S: ::serde::Serializer
+            {
+                [$( AsRef::<[T; $inner]>::as_ref(&self.$field) ),*].serialize(serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de, T> ::serde::Deserialize<'de> for $name<T>
+            where T: ::serde::Deserialize<'de>
+        {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where D: ::serde::Deserializer<'de>
+            {
+                <[[T; $inner]; $outer]>::deserialize(deserializer).map($name::<T>::from)
+            }
+        }
+    };
+}
+
+aligned!(
+    /// 2D vector aligned to 16 bytes, as a matrix column in the std140 layout.
+    Vector2A [x, y] = [T; 2], Vector2
+);
+aligned!(
+    /// 3D vector aligned to 16 bytes, as `vec3` in the std140 and std430 layouts.
+    ///
+    /// For 32-bit components it takes 16 bytes, with the padding at the end.
+    Vector3A [x, y, z] = [T; 3], Vector3
+);
+
+padded_matrix!(
+    /// 2x2 column-major matrix with columns padded to 16 bytes, as `mat2` in the std140 layout.
+    ///
+    /// In the std430 layout `mat2` matches the unpadded `ColumnMatrix2`.
+    ColumnMatrix2A : Vector2A [x, y] = (2, 2), ColumnMatrix2
+);
+padded_matrix!(
+    /// 3x3 column-major matrix with columns padded to 16 bytes,
+    /// as `mat3` in the std140 and std430 layouts.
+    ColumnMatrix3A : Vector3A [x, y, z] = (3, 3), ColumnMatrix3
+);
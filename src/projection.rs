@@ -0,0 +1,349 @@
+use crate::angle::Rad;
+#[cfg(feature = "num-traits")]
+use crate::matrix::{ColumnMatrix4, RowMatrix4};
+use crate::{AsMint, AsMintMut, IntoMint};
+#[cfg(feature = "num-traits")]
+use num_traits::Float;
+
+/// Range of the depth values in normalized device coordinates.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum DepthRange {
+    /// Near plane maps to 0, far plane to 1, as in Direct3D, Metal, and Vulkan.
+    ZeroToOne,
+    /// Near plane maps to -1, far plane to 1, as in OpenGL.
+    NegativeOneToOne,
+    /// Near plane maps to 1, far plane to 0, also known as "reversed Z".
+    OneToZero,
+}
+
+/// Handedness of the view space.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Handedness {
+    /// The camera looks towards negative Z, as in OpenGL.
+    Right,
+    /// The camera looks towards positive Z, as in Direct3D.
+    Left,
+}
+
+/// Symmetric perspective projection.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
+pub struct Perspective<T> {
+    /// Vertical field of view.
+    pub fov_y: Rad<T>,
+    /// Ratio of the width to the height of the viewport.
+    pub aspect: T,
+    /// Distance to the near plane.
+    pub near: T,
+    /// Distance to the far plane, or `None` for an infinite one.
+    pub far: Option<T>,
+}
+
+/// Asymmetric perspective projection, given by the angles of the frustum sides
+/// as in OpenXR. The left and down angles are typically negative.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
+pub struct PerspectiveFov<T> {
+    /// Angle of the left side of the frustum.
+    pub angle_left: Rad<T>,
+    /// Angle of the right side of the frustum.
+    pub angle_right: Rad<T>,
+    /// Angle of the top side of the frustum.
+    pub angle_up: Rad<T>,
+    /// Angle of the bottom side of the frustum.
+    pub angle_down: Rad<T>,
+    /// Distance to the near plane.
+    pub near: T,
+    /// Distance to the far plane, or `None` for an infinite one.
+    pub far: Option<T>,
+}
+
+/// Orthographic projection, given by the extents of the view volume.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
+#[repr(C)]
+pub struct Orthographic<T> {
+    /// Left side of the view volume.
+    pub left: T,
+    /// Right side of the view volume.
+    pub right: T,
+    /// Bottom side of the view volume.
+    pub bottom: T,
+    /// Top side of the view volume.
+    pub top: T,
+    /// Distance to the near plane.
+    pub near: T,
+    /// Distance to the far plane.
+    pub far: T,
+}
+
+impl<T> IntoMint for Perspective<T> {
+    type MintType = Perspective<T>;
+}
+
+unsafe impl<T> AsMint for Perspective<T> {}
+
+unsafe impl<T> AsMintMut for Perspective<T> {}
+
+impl<T> IntoMint for PerspectiveFov<T> {
+    type MintType = PerspectiveFov<T>;
+}
+
+unsafe impl<T> AsMint for PerspectiveFov<T> {}
+
+unsafe impl<T> AsMintMut for PerspectiveFov<T> {}
+
+impl<T> IntoMint for Orthographic<T> {
+    type MintType = Orthographic<T>;
+}
+
+unsafe impl<T> AsMint for Orthographic<T> {}
+
+unsafe impl<T> AsMintMut for Orthographic<T> {}
+
+impl<T> From<[T; 6]> for Orthographic<T> {
+    fn from([left, right, bottom, top, near, far]: [T; 6]) -> Self {
+        Orthographic {
+            left,
+            right,
+            bottom,
+            top,
+            near,
+            far,
+        }
+    }
+}
+
+impl<T> From<Orthographic<T>> for [T; 6] {
+    fn from(o: Orthographic<T>) -> [T; 6] {
+        [o.left, o.right, o.bottom, o.top, o.near, o.far]
+    }
+}
+
+impl<T> AsRef<[T; 6]> for Orthographic<T> {
+    fn as_ref(&self) -> &[T; 6] {
+        unsafe { ::core::mem::transmute(self) }
+    }
+}
+
+impl<T> AsMut<[T; 6]> for Orthographic<T> {
+    fn as_mut(&mut self) -> &mut [T; 6] {
+        unsafe { ::core::mem::transmute(self) }
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl DepthRange {
+    fn near_far<T: Float>(self) -> (T, T) {
+        match self {
+            DepthRange::ZeroToOne => (T::zero(), T::one()),
+            DepthRange::NegativeOneToOne => (-T::one(), T::one()),
+            DepthRange::OneToZero => (T::one(), T::zero()),
+        }
+    }
+}
+
+/// Builds the rows of a projection matrix from the right-handed form.
+/// The left-handed form has the Z column negated.
+#[cfg(feature = "num-traits")]
+fn projection_rows<T: Float>(mut rows: [[T; 4]; 4], handedness: Handedness) -> RowMatrix4<T> {
+    if handedness == Handedness::Left {
+        for row in rows.iter_mut() {
+            row[2] = -row[2];
+        }
+    }
+    RowMatrix4::from(rows)
+}
+
+#[cfg(feature = "num-traits")]
+impl<T: Float> From<Perspective<T>> for PerspectiveFov<T> {
+    fn from(p: Perspective<T>) -> Self {
+        let half = p.fov_y.0 / (T::one() + T::one());
+        let horizontal = (half.tan() * p.aspect).atan();
+        PerspectiveFov {
+            angle_left: Rad(-horizontal),
+            angle_right: Rad(horizontal),
+            angle_up: Rad(half),
+            angle_down: Rad(-half),
+            near: p.near,
+            far: p.far,
+        }
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl<T: Float> PerspectiveFov<T> {
+    /// Returns the projection matrix in row-major form.
+    pub fn into_row_matrix(self, depth: DepthRange, handedness: Handedness) -> RowMatrix4<T> {
+        let zero = T::zero();
+        let two = T::one() + T::one();
+        let (left, right) = (self.angle_left.0.tan(), self.angle_right.0.tan());
+        let (down, up) = (self.angle_down.0.tan(), self.angle_up.0.tan());
+        let (depth_near, depth_far) = depth.near_far::<T>();
+        let n = self.near;
+        // Solves for the depth of `-near` and `-far` view space Z.
+        let a = match self.far {
+            Some(f) => (n * depth_near - f * depth_far) / (f - n),
+            None => -depth_far,
+        };
+        let b = n * (depth_near + a);
+        projection_rows(
+            [
+                [
+                    two / (right - left),
+                    zero,
+                    (right + left) / (right - left),
+                    zero,
+                ],
+                [zero, two / (up - down), (up + down) / (up - down), zero],
+                [zero, zero, a, b],
+                [zero, zero, -T::one(), zero],
+            ],
+            handedness,
+        )
+    }
+
+    /// Returns the projection matrix in column-major form.
+    pub fn into_column_matrix(self, depth: DepthRange, handedness: Handedness) -> ColumnMatrix4<T> {
+        self.into_row_matrix(depth, handedness).into()
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl<T: Float> Perspective<T> {
+    /// Returns the projection matrix in row-major form.
+    pub fn into_row_matrix(self, depth: DepthRange, handedness: Handedness) -> RowMatrix4<T> {
+        PerspectiveFov::from(self).into_row_matrix(depth, handedness)
+    }
+
+    /// Returns the projection matrix in column-major form.
+    pub fn into_column_matrix(self, depth: DepthRange, handedness: Handedness) -> ColumnMatrix4<T> {
+        self.into_row_matrix(depth, handedness).into()
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl<T: Float> Orthographic<T> {
+    /// Returns the projection matrix in row-major form.
+    pub fn into_row_matrix(self, depth: DepthRange, handedness: Handedness) -> RowMatrix4<T> {
+        let zero = T::zero();
+        let two = T::one() + T::one();
+        let (width, height) = (self.right - self.left, self.top - self.bottom);
+        let (depth_near, depth_far) = depth.near_far::<T>();
+        // Solves for the depth of `-near` and `-far` view space Z.
+        let a = (depth_near - depth_far) / (self.far - self.near);
+        let b = depth_near + a * self.near;
+        projection_rows(
+            [
+                [two / width, zero, zero, -(self.right + self.left) / width],
+                [zero, two / height, zero, -(self.top + self.bottom) / height],
+                [zero, zero, a, b],
+                [zero, zero, zero, T::one()],
+            ],
+            handedness,
+        )
+    }
+
+    /// Returns the projection matrix in column-major form.
+    pub fn into_column_matrix(self, depth: DepthRange, handedness: Handedness) -> ColumnMatrix4<T> {
+        self.into_row_matrix(depth, handedness).into()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T> ::serde::Serialize for Perspective<T>
+where
+    T: ::serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        (&self.fov_y, &self.aspect, &self.near, &self.far).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> ::serde::Deserialize<'de> for Perspective<T>
+where
+    T: ::serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let (fov_y, aspect, near, far) = ::serde::Deserialize::deserialize(deserializer)?;
+        Ok(Perspective {
+            fov_y,
+            aspect,
+            near,
+            far,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T> ::serde::Serialize for PerspectiveFov<T>
+where
+    T: ::serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        (
+            &self.angle_left,
+            &self.angle_right,
+            &self.angle_up,
+            &self.angle_down,
+            &self.near,
+            &self.far,
+        )
+            .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> ::serde::Deserialize<'de> for PerspectiveFov<T>
+where
+    T: ::serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let (angle_left, angle_right, angle_up, angle_down, near, far) =
+            ::serde::Deserialize::deserialize(deserializer)?;
+        Ok(PerspectiveFov {
+            angle_left,
+            angle_right,
+            angle_up,
+            angle_down,
+            near,
+            far,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T> ::serde::Serialize for Orthographic<T>
+where
+    T: ::serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        AsRef::<[T; 6]>::as_ref(self).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> ::serde::Deserialize<'de> for Orthographic<T>
+where
+    T: ::serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        <[T; 6]>::deserialize(deserializer).map(Orthographic::<T>::from)
+    }
+}
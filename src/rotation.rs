@@ -1,10 +1,11 @@
 use crate::vector::Vector3;
-use crate::IntoMint;
+use crate::{AsMint, AsMintMut, IntoMint};
 use core::marker::PhantomData;
 
 /// Standard quaternion represented by the scalar and vector parts.
 /// Useful for representing rotation in 3D space.
 /// Corresponds to a right-handed rotation matrix.
+/// Use `Tagged` with a `CoordinateSystem` to state the convention explicitly.
 #[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
 #[repr(C)]
 pub struct Quaternion<T> {
@@ -18,6 +19,18 @@ impl<T> IntoMint for Quaternion<T> {
     type MintType = Quaternion<T>;
 }
 
+unsafe impl<T> AsMint for Quaternion<T> {}
+
+unsafe impl<T> AsMintMut for Quaternion<T> {}
+
+impl<T, B> IntoMint for EulerAngles<T, B> {
+    type MintType = EulerAngles<T, B>;
+}
+
+unsafe impl<T, B> AsMint for EulerAngles<T, B> {}
+
+unsafe impl<T, B> AsMintMut for EulerAngles<T, B> {}
+
 impl<T> From<[T; 4]> for Quaternion<T> {
     fn from([x, y, z, s]: [T; 4]) -> Self {
         Quaternion {
@@ -78,7 +91,11 @@ where
 /// split in two groups:
 ///   - intrinsic (also known as "Tait-Bryan angles"): rotate around local axis
 ///   - extrinsic (also known as "Proper Euler angles"): rotate around world axis
+///
 /// For each interpretation, different axis may be chosen in different order.
+///
+/// The angles are stored in radians. Use `Rad` and `Deg` arrays to
+/// make the unit explicit when constructing or reading them.
 #[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
 #[repr(C)]
 pub struct EulerAngles<T, B> {
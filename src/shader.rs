@@ -0,0 +1,273 @@
+use crate::matrix::*;
+use crate::rotation::Quaternion;
+use crate::vector::*;
+use core::fmt;
+use core::marker::PhantomData;
+
+/// Shading language to write values in.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ShaderLanguage {
+    /// WebGPU Shading Language.
+    Wgsl,
+    /// OpenGL Shading Language.
+    Glsl,
+    /// High-Level Shading Language of Direct3D.
+    Hlsl,
+}
+
+/// Scalar type of shader vectors.
+pub trait ShaderScalar {
+    /// Name of the type in WGSL.
+    const WGSL: &'static str;
+    /// Prefix of the vector types in GLSL, such as `i` in `ivec3`.
+    const GLSL_PREFIX: &'static str;
+    /// Name of the type in HLSL.
+    const HLSL: &'static str;
+
+    /// Writes the value as a literal of the type in the language.
+    fn fmt_literal(&self, language: ShaderLanguage, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+/// Scalar type of shader matrices.
+pub trait ShaderFloat: ShaderScalar {}
+
+impl ShaderScalar for f32 {
+    const WGSL: &'static str = "f32";
+    const GLSL_PREFIX: &'static str = "";
+    const HLSL: &'static str = "float";
+
+    fn fmt_literal(&self, language: ShaderLanguage, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Infinities and NaN have no literal, so their bits are reinterpreted.
+        if !self.is_finite() {
+            let bitcast = match language {
+                ShaderLanguage::Wgsl => "bitcast<f32>",
+                ShaderLanguage::Glsl => "uintBitsToFloat",
+                ShaderLanguage::Hlsl => "asfloat",
+            };
+            return write!(f, "{}({:#010x}u)", bitcast, self.to_bits());
+        }
+        // The debug form always has a decimal point or an exponent.
+        write!(f, "{:?}", self)
+    }
+}
+
+impl ShaderFloat for f32 {}
+
+impl ShaderScalar for i32 {
+    const WGSL: &'static str = "i32";
+    const GLSL_PREFIX: &'static str = "i";
+    const HLSL: &'static str = "int";
+
+    fn fmt_literal(&self, _: ShaderLanguage, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+impl ShaderScalar for u32 {
+    const WGSL: &'static str = "u32";
+    const GLSL_PREFIX: &'static str = "u";
+    const HLSL: &'static str = "uint";
+
+    fn fmt_literal(&self, _: ShaderLanguage, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}u", self)
+    }
+}
+
+/// Mint types that can be written as shader values.
+pub trait ShaderValue {
+    /// Writes the name of the type in the language.
+    fn fmt_type(language: ShaderLanguage, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+
+    /// Writes the constructor arguments in the order of the language.
+    fn fmt_arguments(&self, language: ShaderLanguage, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+
+    /// Returns the name of the type in the language, such as `vec3<f32>`.
+    fn shader_type(language: ShaderLanguage) -> ShaderType<Self> {
+        ShaderType {
+            language,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the value as a literal in the language, such as `vec3<f32>(1.0, 2.0, 3.0)`.
+    fn shader_literal(&self, language: ShaderLanguage) -> ShaderLiteral<'_, Self> {
+        ShaderLiteral {
+            value: self,
+            language,
+        }
+    }
+}
+
+/// Name of a shader type, formatted with `Display`.
+pub struct ShaderType<V: ?Sized> {
+    language: ShaderLanguage,
+    marker: PhantomData<V>,
+}
+
+impl<V: ShaderValue + ?Sized> fmt::Display for ShaderType<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        V::fmt_type(self.language, f)
+    }
+}
+
+/// Literal of a shader value, formatted with `Display`.
+///
+/// Infinite and NaN components, which have no literal, are written as their bits
+/// reinterpreted as a float, such as `bitcast<f32>(0x7fc00000u)` for NaN in WGSL.
+pub struct ShaderLiteral<'a, V: ?Sized> {
+    value: &'a V,
+    language: ShaderLanguage,
+}
+
+impl<V: ShaderValue + ?Sized> fmt::Display for ShaderLiteral<'_, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        V::fmt_type(self.language, f)?;
+        f.write_str("(")?;
+        self.value.fmt_arguments(self.language, f)?;
+        f.write_str(")")
+    }
+}
+
+fn fmt_vector_type<T: ShaderScalar>(
+    language: ShaderLanguage,
+    size: usize,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    match language {
+        ShaderLanguage::Wgsl => write!(f, "vec{}<{}>", size, T::WGSL),
+        ShaderLanguage::Glsl => write!(f, "{}vec{}", T::GLSL_PREFIX, size),
+        ShaderLanguage::Hlsl => write!(f, "{}{}", T::HLSL, size),
+    }
+}
+
+fn fmt_list<'a, T: ShaderScalar + 'a, I>(
+    language: ShaderLanguage,
+    f: &mut fmt::Formatter<'_>,
+    values: I,
+) -> fmt::Result
+where
+    I: IntoIterator<Item = &'a T>,
+{
+    for (i, v) in values.into_iter().enumerate() {
+        if i != 0 {
+            f.write_str(", ")?;
+        }
+        v.fmt_literal(language, f)?;
+    }
+    Ok(())
+}
+
+/// Writes the matrix type, whose size is named columns first
+/// in WGSL and GLSL, and rows first in HLSL.
+fn fmt_matrix_type<T: ShaderFloat>(
+    language: ShaderLanguage,
+    (rows, columns): (usize, usize),
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    match language {
+        ShaderLanguage::Wgsl => write!(f, "mat{}x{}<{}>", columns, rows, T::WGSL),
+        ShaderLanguage::Glsl if rows == columns => write!(f, "{}mat{}", T::GLSL_PREFIX, rows),
+        ShaderLanguage::Glsl => write!(f, "{}mat{}x{}", T::GLSL_PREFIX, columns, rows),
+        ShaderLanguage::Hlsl => write!(f, "{}{}x{}", T::HLSL, rows, columns),
+    }
+}
+
+/// Writes the matrix components column by column in WGSL and GLSL,
+/// and row by row in HLSL, whatever the storage order.
+fn fmt_matrix_arguments<T: ShaderFloat>(
+    language: ShaderLanguage,
+    flat: &[T],
+    (rows, columns): (usize, usize),
+    row_major: bool,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    let element = |r: usize, c: usize| {
+        if row_major {
+            &flat[r * columns + c]
+        } else {
+            &flat[c * rows + r]
+        }
+    };
+    match language {
+        ShaderLanguage::Wgsl | ShaderLanguage::Glsl => fmt_list(
+            language,
+            f,
+            (0..columns).flat_map(|c| (0..rows).map(move |r| element(r, c))),
+        ),
+        ShaderLanguage::Hlsl => fmt_list(
+            language,
+            f,
+            (0..rows).flat_map(|r| (0..columns).map(move |c| element(r, c))),
+        ),
+    }
+}
+
+macro_rules! shader_vector {
+    ($($name:ident : $n:expr),*) => {
+        $(
+            impl<T: ShaderScalar> ShaderValue for $name<T> {
+                fn fmt_type(language: ShaderLanguage, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt_vector_type::<T>(language, $n, f)
+                }
+
+                fn fmt_arguments(&self, language: ShaderLanguage, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt_list(language, f, AsRef::<[T; $n]>::as_ref(self))
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! shader_matrix {
+    ($($name:ident : ($rows:expr, $columns:expr) $major:ident),*) => {
+        $(
+            impl<T: ShaderFloat> ShaderValue for $name<T> {
+                fn fmt_type(language: ShaderLanguage, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt_matrix_type::<T>(language, ($rows, $columns), f)
+                }
+
+                fn fmt_arguments(&self, language: ShaderLanguage, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    let flat = AsRef::<[T; $rows * $columns]>::as_ref(self);
+                    fmt_matrix_arguments(language, flat, ($rows, $columns), shader_matrix!(@row_major $major), f)
+                }
+            }
+        )*
+    };
+    (@row_major rows) => { true };
+    (@row_major columns) => { false };
+}
+
+shader_vector!(
+    Vector2: 2,
+    Vector3: 3,
+    Vector4: 4,
+    Point2: 2,
+    Point3: 3,
+    Size2: 2,
+    Size3: 3,
+    Offset2: 2
+);
+
+// Shading languages have no quaternion type, so it's written as `(x, y, z, s)`.
+shader_vector!(Quaternion: 4);
+
+shader_matrix!(
+    RowMatrix2: (2, 2) rows,
+    RowMatrix2x3: (2, 3) rows,
+    RowMatrix2x4: (2, 4) rows,
+    RowMatrix3x2: (3, 2) rows,
+    RowMatrix3: (3, 3) rows,
+    RowMatrix3x4: (3, 4) rows,
+    RowMatrix4x2: (4, 2) rows,
+    RowMatrix4x3: (4, 3) rows,
+    RowMatrix4: (4, 4) rows,
+    ColumnMatrix2: (2, 2) columns,
+    ColumnMatrix2x3: (2, 3) columns,
+    ColumnMatrix2x4: (2, 4) columns,
+    ColumnMatrix3x2: (3, 2) columns,
+    ColumnMatrix3: (3, 3) columns,
+    ColumnMatrix3x4: (3, 4) columns,
+    ColumnMatrix4x2: (4, 2) columns,
+    ColumnMatrix4x3: (4, 3) columns,
+    ColumnMatrix4: (4, 4) columns
+);
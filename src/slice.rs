@@ -0,0 +1,117 @@
+use crate::layout::MintLayout;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::slice;
+
+/// Reinterprets a slice of mint values as a flat slice of their components.
+pub fn flatten<M: MintLayout>(values: &[M]) -> &[M::Scalar] {
+    let len = values.len() * M::COMPONENTS;
+    unsafe { slice::from_raw_parts(values.as_ptr() as *const M::Scalar, len) }
+}
+
+/// Reinterprets a mutable slice of mint values as a flat slice of their components.
+pub fn flatten_mut<M: MintLayout>(values: &mut [M]) -> &mut [M::Scalar] {
+    let len = values.len() * M::COMPONENTS;
+    unsafe { slice::from_raw_parts_mut(values.as_mut_ptr() as *mut M::Scalar, len) }
+}
+
+/// Reinterprets a flat slice of components as a slice of mint values.
+///
+/// Returns `None` if the length is not a multiple of the number of components.
+pub fn unflatten<M: MintLayout>(scalars: &[M::Scalar]) -> Option<&[M]> {
+    let len = split_len::<M>(scalars.len())?;
+    Some(unsafe { slice::from_raw_parts(scalars.as_ptr() as *const M, len) })
+}
+
+/// Reinterprets a flat mutable slice of components as a slice of mint values.
+///
+/// Returns `None` if the length is not a multiple of the number of components.
+pub fn unflatten_mut<M: MintLayout>(scalars: &mut [M::Scalar]) -> Option<&mut [M]> {
+    let len = split_len::<M>(scalars.len())?;
+    Some(unsafe { slice::from_raw_parts_mut(scalars.as_mut_ptr() as *mut M, len) })
+}
+
+/// Reinterprets a slice of mint values as a slice of component arrays.
+///
+/// Returns `None` if `N` is not the number of components.
+pub fn as_arrays<M: MintLayout, const N: usize>(values: &[M]) -> Option<&[[M::Scalar; N]]> {
+    if N != M::COMPONENTS {
+        return None;
+    }
+    Some(unsafe { slice::from_raw_parts(values.as_ptr() as *const _, values.len()) })
+}
+
+/// Reinterprets a mutable slice of mint values as a slice of component arrays.
+///
+/// Returns `None` if `N` is not the number of components.
+pub fn as_arrays_mut<M: MintLayout, const N: usize>(
+    values: &mut [M],
+) -> Option<&mut [[M::Scalar; N]]> {
+    if N != M::COMPONENTS {
+        return None;
+    }
+    Some(unsafe { slice::from_raw_parts_mut(values.as_mut_ptr() as *mut _, values.len()) })
+}
+
+/// Reinterprets a slice of component arrays as a slice of mint values.
+///
+/// Returns `None` if `N` is not the number of components.
+pub fn from_arrays<M: MintLayout, const N: usize>(arrays: &[[M::Scalar; N]]) -> Option<&[M]> {
+    if N != M::COMPONENTS {
+        return None;
+    }
+    Some(unsafe { slice::from_raw_parts(arrays.as_ptr() as *const M, arrays.len()) })
+}
+
+/// Reinterprets a mutable slice of component arrays as a slice of mint values.
+///
+/// Returns `None` if `N` is not the number of components.
+pub fn from_arrays_mut<M: MintLayout, const N: usize>(
+    arrays: &mut [[M::Scalar; N]],
+) -> Option<&mut [M]> {
+    if N != M::COMPONENTS {
+        return None;
+    }
+    Some(unsafe { slice::from_raw_parts_mut(arrays.as_mut_ptr() as *mut M, arrays.len()) })
+}
+
+/// Converts a vector of mint values into a flat vector of their components,
+/// reusing the allocation.
+#[cfg(feature = "alloc")]
+pub fn flatten_vec<M: MintLayout>(values: Vec<M>) -> Vec<M::Scalar> {
+    let mut values = core::mem::ManuallyDrop::new(values);
+    let (len, capacity) = (values.len(), values.capacity());
+    unsafe {
+        Vec::from_raw_parts(
+            values.as_mut_ptr() as *mut M::Scalar,
+            len * M::COMPONENTS,
+            capacity * M::COMPONENTS,
+        )
+    }
+}
+
+/// Converts a flat vector of components into a vector of mint values,
+/// reusing the allocation.
+///
+/// Fails if the length or the capacity is not a multiple of the number
+/// of components, returning the vector back.
+#[cfg(feature = "alloc")]
+pub fn unflatten_vec<M: MintLayout>(scalars: Vec<M::Scalar>) -> Result<Vec<M>, Vec<M::Scalar>> {
+    let (len, capacity) = match (
+        split_len::<M>(scalars.len()),
+        split_len::<M>(scalars.capacity()),
+    ) {
+        (Some(len), Some(capacity)) => (len, capacity),
+        _ => return Err(scalars),
+    };
+    let mut scalars = core::mem::ManuallyDrop::new(scalars);
+    Ok(unsafe { Vec::from_raw_parts(scalars.as_mut_ptr() as *mut M, len, capacity) })
+}
+
+/// Returns the number of mint values made of `len` components, if it's exact.
+fn split_len<M: MintLayout>(len: usize) -> Option<usize> {
+    match len.checked_rem(M::COMPONENTS) {
+        Some(0) => Some(len / M::COMPONENTS),
+        _ => None,
+    }
+}
@@ -0,0 +1,214 @@
+use crate::vector::{Point2, Point3, Vector2, Vector3, Vector4};
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+
+macro_rules! soa {
+    ($(#[$attr:meta])* $name:ident : $vec:ident [ $first:ident $(, $field:ident)* ]) => {
+        $(#[$attr])*
+        #[derive(Clone, Debug, Hash, PartialEq, Eq)]
+        pub struct $name<T> {
+            $first: Vec<T>,
+            $( $field: Vec<T>, )*
+        }
+
+        impl<T> Default for $name<T> {
+            fn default() -> Self {
+                $name::new()
+            }
+        }
+
+        impl<T> $name<T> {
+            /// Creates an empty container.
+            pub fn new() -> Self {
+                $name {
+                    $first: Vec::new(),
+                    $( $field: Vec::new(), )*
+                }
+            }
+
+            /// Creates an empty container with space for `capacity` values.
+            pub fn with_capacity(capacity: usize) -> Self {
+                $name {
+                    $first: Vec::with_capacity(capacity),
+                    $( $field: Vec::with_capacity(capacity), )*
+                }
+            }
+
+            /// Creates a container from the component streams.
+            ///
+            /// Fails if the streams differ in length, returning them back.
+            pub fn from_components(components: $vec<Vec<T>>) -> Result<Self, $vec<Vec<T>>> {
+                let len = components.$first.len();
+                if [$( components.$field.len() ),*].iter().any(|&l| l != len) {
+                    return Err(components);
+                }
+                Ok($name {
+                    $first: components.$first,
+                    $( $field: components.$field, )*
+                })
+            }
+
+            /// Returns the component streams.
+            pub fn into_components(self) -> $vec<Vec<T>> {
+                $vec {
+                    $first: self.$first,
+                    $( $field: self.$field, )*
+                }
+            }
+
+            /// Returns the component streams as slices.
+            pub fn as_slices(&self) -> $vec<&[T]> {
+                $vec {
+                    $first: &self.$first,
+                    $( $field: &self.$field, )*
+                }
+            }
+
+            /// Returns the component streams as mutable slices.
+            pub fn as_mut_slices(&mut self) -> $vec<&mut [T]> {
+                $vec {
+                    $first: &mut self.$first,
+                    $( $field: &mut self.$field, )*
+                }
+            }
+
+            /// Returns the number of values.
+            pub fn len(&self) -> usize {
+                self.$first.len()
+            }
+
+            /// Returns `true` if there are no values.
+            pub fn is_empty(&self) -> bool {
+                self.$first.is_empty()
+            }
+
+            /// Appends a value to the end.
+            pub fn push(&mut self, value: $vec<T>) {
+                self.$first.push(value.$first);
+                $( self.$field.push(value.$field); )*
+            }
+
+            /// Removes the last value and returns it, or `None` if empty.
+            pub fn pop(&mut self) -> Option<$vec<T>> {
+                let $first = self.$first.pop()?;
+                $( let $field = self.$field.pop()?; )*
+                Some($vec { $first $(, $field)* })
+            }
+
+            /// Replaces the value at `index`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `index` is out of bounds.
+            pub fn set(&mut self, index: usize, value: $vec<T>) {
+                self.$first[index] = value.$first;
+                $( self.$field[index] = value.$field; )*
+            }
+
+            /// Removes all values.
+            pub fn clear(&mut self) {
+                self.$first.clear();
+                $( self.$field.clear(); )*
+            }
+        }
+
+        impl<T: Clone> $name<T> {
+            /// Returns the value at `index`, or `None` if it's out of bounds.
+            pub fn get(&self, index: usize) -> Option<$vec<T>> {
+                Some($vec {
+                    $first: self.$first.get(index)?.clone(),
+                    $( $field: self.$field.get(index)?.clone(), )*
+                })
+            }
+
+            /// Returns an iterator over the values, zipping the component streams.
+            pub fn iter(&self) -> impl ExactSizeIterator<Item = $vec<T>> + '_ {
+                let $first = self.$first.iter();
+                $( let mut $field = self.$field.iter(); )*
+                $first.map(move |$first| $vec {
+                    $first: $first.clone(),
+                    $( $field: $field.next().unwrap().clone(), )*
+                })
+            }
+
+            /// Converts the values into an array of structures.
+            pub fn to_vec(&self) -> Vec<$vec<T>> {
+                self.iter().collect()
+            }
+
+            /// Appends the values of an array of structures.
+            pub fn extend_from_slice(&mut self, values: &[$vec<T>]) {
+                self.$first.extend(values.iter().map(|v| v.$first.clone()));
+                $( self.$field.extend(values.iter().map(|v| v.$field.clone())); )*
+            }
+        }
+
+        impl<T> Extend<$vec<T>> for $name<T> {
+            fn extend<I: IntoIterator<Item = $vec<T>>>(&mut self, iter: I) {
+                let iter = iter.into_iter();
+                let (lower, _) = iter.size_hint();
+                self.$first.reserve(lower);
+                $( self.$field.reserve(lower); )*
+                for value in iter {
+                    self.push(value);
+                }
+            }
+        }
+
+        impl<T> FromIterator<$vec<T>> for $name<T> {
+            fn from_iter<I: IntoIterator<Item = $vec<T>>>(iter: I) -> Self {
+                let mut soa = $name::new();
+                soa.extend(iter);
+                soa
+            }
+        }
+
+        impl<T: Clone> From<&[$vec<T>]> for $name<T> {
+            fn from(values: &[$vec<T>]) -> Self {
+                let mut soa = $name::with_capacity(values.len());
+                soa.extend_from_slice(values);
+                soa
+            }
+        }
+
+        impl<T> From<Vec<$vec<T>>> for $name<T> {
+            fn from(values: Vec<$vec<T>>) -> Self {
+                values.into_iter().collect()
+            }
+        }
+
+        impl<T> From<$name<T>> for Vec<$vec<T>> {
+            fn from(soa: $name<T>) -> Self {
+                let $first = soa.$first.into_iter();
+                $( let mut $field = soa.$field.into_iter(); )*
+                $first
+                    .map(|$first| $vec {
+                        $first,
+                        $( $field: $field.next().unwrap(), )*
+                    })
+                    .collect()
+            }
+        }
+    };
+}
+
+soa!(
+    /// Container of 2D vectors, storing each component in its own stream.
+    Vector2Soa : Vector2 [x, y]
+);
+soa!(
+    /// Container of 3D vectors, storing each component in its own stream.
+    Vector3Soa : Vector3 [x, y, z]
+);
+soa!(
+    /// Container of 4D vectors, storing each component in its own stream.
+    Vector4Soa : Vector4 [x, y, z, w]
+);
+soa!(
+    /// Container of 2D points, storing each component in its own stream.
+    Point2Soa : Point2 [x, y]
+);
+soa!(
+    /// Container of 3D points, storing each component in its own stream.
+    Point3Soa : Point3 [x, y, z]
+);
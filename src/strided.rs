@@ -0,0 +1,356 @@
+use crate::layout::MintLayout;
+use core::fmt;
+use core::marker::PhantomData;
+use core::mem;
+use core::ops::{Index, IndexMut};
+
+/// Reason for rejecting a strided view of a byte buffer.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum StrideError {
+    /// The stride is smaller than the element, so the elements would overlap.
+    Overlapping,
+    /// The last element ends past the end of the buffer.
+    OutOfBounds,
+    /// The offset or the stride breaks the alignment of the element.
+    Misaligned,
+}
+
+impl fmt::Display for StrideError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match *self {
+            StrideError::Overlapping => "stride is smaller than the element",
+            StrideError::OutOfBounds => "elements extend past the end of the buffer",
+            StrideError::Misaligned => "elements are not aligned in the buffer",
+        })
+    }
+}
+
+/// Checks that `count` elements of `M` fit into the buffer at the given offset and stride.
+fn validate<M: MintLayout>(
+    data: *const u8,
+    len: usize,
+    offset: usize,
+    stride: usize,
+    count: usize,
+) -> Result<(), StrideError> {
+    if count == 0 {
+        return Ok(());
+    }
+    if stride < mem::size_of::<M>() {
+        return Err(StrideError::Overlapping);
+    }
+    let end = (count - 1)
+        .checked_mul(stride)
+        .and_then(|start| start.checked_add(offset))
+        .and_then(|start| start.checked_add(mem::size_of::<M>()));
+    match end {
+        Some(end) if end <= len => {}
+        _ => return Err(StrideError::OutOfBounds),
+    }
+    let align = mem::align_of::<M>();
+    // Alignments are powers of two.
+    if ((data as usize).wrapping_add(offset) | stride) & (align - 1) != 0 {
+        return Err(StrideError::Misaligned);
+    }
+    Ok(())
+}
+
+/// View of mint values interleaved in a byte buffer, such as
+/// the positions of a vertex buffer or a glTF accessor.
+pub struct StridedSlice<'a, M> {
+    data: *const u8,
+    stride: usize,
+    len: usize,
+    marker: PhantomData<&'a [M]>,
+}
+
+unsafe impl<M: Sync> Send for StridedSlice<'_, M> {}
+unsafe impl<M: Sync> Sync for StridedSlice<'_, M> {}
+
+impl<M> Clone for StridedSlice<'_, M> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<M> Copy for StridedSlice<'_, M> {}
+
+impl<'a, M: MintLayout> StridedSlice<'a, M> {
+    /// Creates a view of `count` values, the first one starting at `offset` bytes
+    /// into the buffer, and each next one `stride` bytes after the previous.
+    pub fn new(
+        data: &'a [u8],
+        offset: usize,
+        stride: usize,
+        count: usize,
+    ) -> Result<Self, StrideError> {
+        validate::<M>(data.as_ptr(), data.len(), offset, stride, count)?;
+        Ok(StridedSlice {
+            data: data.as_ptr().wrapping_add(offset),
+            stride,
+            len: count,
+            marker: PhantomData,
+        })
+    }
+
+    /// Returns the number of values.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no values.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the value at `index`, or `None` if it's out of bounds.
+    pub fn get(&self, index: usize) -> Option<&'a M> {
+        if index < self.len {
+            Some(unsafe { &*(self.data.add(index * self.stride) as *const M) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the values.
+    pub fn iter(&self) -> StridedIter<'a, M> {
+        StridedIter {
+            slice: *self,
+            front: 0,
+        }
+    }
+
+    /// Copies the values into `dst`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `dst` differs from the number of values.
+    pub fn copy_to_slice(&self, dst: &mut [M])
+    where
+        M: Copy,
+    {
+        assert_eq!(dst.len(), self.len, "Destination length mismatch.");
+        for (d, s) in dst.iter_mut().zip(self.iter()) {
+            *d = *s;
+        }
+    }
+}
+
+impl<M: MintLayout> Index<usize> for StridedSlice<'_, M> {
+    type Output = M;
+
+    fn index(&self, index: usize) -> &M {
+        self.get(index).expect("Index out of bounds.")
+    }
+}
+
+impl<'a, M: MintLayout> IntoIterator for StridedSlice<'a, M> {
+    type Item = &'a M;
+    type IntoIter = StridedIter<'a, M>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<M: MintLayout + fmt::Debug> fmt::Debug for StridedSlice<'_, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Mutable view of mint values interleaved in a byte buffer.
+pub struct StridedSliceMut<'a, M> {
+    data: *mut u8,
+    stride: usize,
+    len: usize,
+    marker: PhantomData<&'a mut [M]>,
+}
+
+unsafe impl<M: Send> Send for StridedSliceMut<'_, M> {}
+unsafe impl<M: Sync> Sync for StridedSliceMut<'_, M> {}
+
+impl<'a, M: MintLayout> StridedSliceMut<'a, M> {
+    /// Creates a view of `count` values, the first one starting at `offset` bytes
+    /// into the buffer, and each next one `stride` bytes after the previous.
+    pub fn new(
+        data: &'a mut [u8],
+        offset: usize,
+        stride: usize,
+        count: usize,
+    ) -> Result<Self, StrideError> {
+        validate::<M>(data.as_ptr(), data.len(), offset, stride, count)?;
+        Ok(StridedSliceMut {
+            data: data.as_mut_ptr().wrapping_add(offset),
+            stride,
+            len: count,
+            marker: PhantomData,
+        })
+    }
+
+    /// Returns the number of values.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no values.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns an immutable view of the same values.
+    pub fn as_strided_slice(&self) -> StridedSlice<'_, M> {
+        StridedSlice {
+            data: self.data,
+            stride: self.stride,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the value at `index`, or `None` if it's out of bounds.
+    pub fn get(&self, index: usize) -> Option<&M> {
+        self.as_strided_slice().get(index)
+    }
+
+    /// Returns the mutable value at `index`, or `None` if it's out of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut M> {
+        if index < self.len {
+            Some(unsafe { &mut *(self.data.add(index * self.stride) as *mut M) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the values.
+    pub fn iter(&self) -> StridedIter<'_, M> {
+        self.as_strided_slice().iter()
+    }
+
+    /// Returns an iterator over the mutable values.
+    pub fn iter_mut(&mut self) -> StridedIterMut<'_, M> {
+        StridedIterMut {
+            data: self.data,
+            stride: self.stride,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+
+    /// Copies the values into `dst`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `dst` differs from the number of values.
+    pub fn copy_to_slice(&self, dst: &mut [M])
+    where
+        M: Copy,
+    {
+        self.as_strided_slice().copy_to_slice(dst)
+    }
+
+    /// Copies the values from `src`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `src` differs from the number of values.
+    pub fn copy_from_slice(&mut self, src: &[M])
+    where
+        M: Copy,
+    {
+        assert_eq!(src.len(), self.len, "Source length mismatch.");
+        for (d, s) in self.iter_mut().zip(src) {
+            *d = *s;
+        }
+    }
+}
+
+impl<M: MintLayout> Index<usize> for StridedSliceMut<'_, M> {
+    type Output = M;
+
+    fn index(&self, index: usize) -> &M {
+        self.get(index).expect("Index out of bounds.")
+    }
+}
+
+impl<M: MintLayout> IndexMut<usize> for StridedSliceMut<'_, M> {
+    fn index_mut(&mut self, index: usize) -> &mut M {
+        self.get_mut(index).expect("Index out of bounds.")
+    }
+}
+
+impl<'a, M: MintLayout> IntoIterator for StridedSliceMut<'a, M> {
+    type Item = &'a mut M;
+    type IntoIter = StridedIterMut<'a, M>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        StridedIterMut {
+            data: self.data,
+            stride: self.stride,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<M: MintLayout + fmt::Debug> fmt::Debug for StridedSliceMut<'_, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_strided_slice().fmt(f)
+    }
+}
+
+/// Iterator over the values of a `StridedSlice`.
+#[derive(Clone)]
+pub struct StridedIter<'a, M> {
+    slice: StridedSlice<'a, M>,
+    front: usize,
+}
+
+impl<'a, M: MintLayout> Iterator for StridedIter<'a, M> {
+    type Item = &'a M;
+
+    fn next(&mut self) -> Option<&'a M> {
+        let item = self.slice.get(self.front)?;
+        self.front += 1;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.slice.len - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<M: MintLayout> ExactSizeIterator for StridedIter<'_, M> {}
+
+/// Iterator over the mutable values of a `StridedSliceMut`.
+pub struct StridedIterMut<'a, M> {
+    data: *mut u8,
+    stride: usize,
+    len: usize,
+    marker: PhantomData<&'a mut M>,
+}
+
+unsafe impl<M: Send> Send for StridedIterMut<'_, M> {}
+unsafe impl<M: Sync> Sync for StridedIterMut<'_, M> {}
+
+impl<'a, M: MintLayout> Iterator for StridedIterMut<'a, M> {
+    type Item = &'a mut M;
+
+    fn next(&mut self) -> Option<&'a mut M> {
+        if self.len == 0 {
+            return None;
+        }
+        // The elements don't overlap, so each one is handed out only once.
+        let item = unsafe { &mut *(self.data as *mut M) };
+        self.data = self.data.wrapping_add(self.stride);
+        self.len -= 1;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<M: MintLayout> ExactSizeIterator for StridedIterMut<'_, M> {}
@@ -0,0 +1,127 @@
+use crate::{AsMint, AsMintMut, IntoMint};
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+
+/// Value tagged with the space `S` it is expressed in.
+///
+/// The space is a phantom marker type, such as a coordinate space (world, local, screen)
+/// or a `CoordinateSystem`. It doesn't affect the layout or the serialized representation,
+/// and can only be changed explicitly with `retag`.
+#[repr(transparent)]
+pub struct Tagged<V, S> {
+    /// Value in the space.
+    pub value: V,
+    /// Marker for the phantom space.
+    pub marker: PhantomData<S>,
+}
+
+impl<V, S> Tagged<V, S> {
+    /// Declares the space of a value.
+    pub fn new(value: V) -> Self {
+        Tagged {
+            value,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the value, forgetting the space.
+    pub fn into_inner(self) -> V {
+        self.value
+    }
+
+    /// Reinterprets the value as expressed in another space.
+    pub fn retag<To>(self) -> Tagged<V, To> {
+        Tagged::new(self.value)
+    }
+}
+
+impl<V: Clone, S> Clone for Tagged<V, S> {
+    fn clone(&self) -> Self {
+        Tagged::new(self.value.clone())
+    }
+}
+
+impl<V: Copy, S> Copy for Tagged<V, S> {}
+
+impl<V: fmt::Debug, S> fmt::Debug for Tagged<V, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tagged")
+            .field("value", &self.value)
+            .field("marker", &self.marker)
+            .finish()
+    }
+}
+
+impl<V: Hash, S> Hash for Tagged<V, S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state)
+    }
+}
+
+impl<V: PartialEq, S> PartialEq for Tagged<V, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<V: Eq, S> Eq for Tagged<V, S> {}
+
+impl<V: PartialOrd, S> PartialOrd for Tagged<V, S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<V: Ord, S> Ord for Tagged<V, S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<V, S> IntoMint for Tagged<V, S> {
+    type MintType = Tagged<V, S>;
+}
+
+unsafe impl<V, S> AsMint for Tagged<V, S> {}
+
+unsafe impl<V, S> AsMintMut for Tagged<V, S> {}
+
+impl<V, S> AsRef<V> for Tagged<V, S> {
+    fn as_ref(&self) -> &V {
+        &self.value
+    }
+}
+
+impl<V, S> AsMut<V> for Tagged<V, S> {
+    fn as_mut(&mut self) -> &mut V {
+        &mut self.value
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<V, S> ::serde::Serialize for Tagged<V, S>
+where
+    V: ::serde::Serialize,
+{
+    fn serialize<Z>(&self, serializer: Z) -> Result<Z::Ok, Z::Error>
+    where
+        Z: ::serde::Serializer,
+    {
+        self.value.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, V, S> ::serde::Deserialize<'de> for Tagged<V, S>
+where
+    V: ::serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        V::deserialize(deserializer).map(Tagged::new)
+    }
+}
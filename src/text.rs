@@ -0,0 +1,392 @@
+use crate::matrix::*;
+use crate::rotation::{EulerAngles, Quaternion};
+use crate::vector::*;
+use core::fmt;
+use core::mem::MaybeUninit;
+use core::ptr;
+use core::str::FromStr;
+
+/// Error of parsing a mint type from a string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseMintError<E> {
+    /// The number of components is wrong.
+    Count {
+        /// Number of components of the type.
+        expected: usize,
+        /// Number of components in the string.
+        found: usize,
+    },
+    /// A component failed to parse.
+    Component {
+        /// Index of the component, counting row by row for matrices.
+        index: usize,
+        /// Error of parsing the component.
+        error: E,
+    },
+    /// The number of rows of a matrix is wrong.
+    RowCount {
+        /// Number of rows of the type.
+        expected: usize,
+        /// Number of rows in the string.
+        found: usize,
+    },
+    /// A row of a matrix has the wrong number of components.
+    RowLength {
+        /// Index of the row.
+        row: usize,
+        /// Number of columns of the type.
+        expected: usize,
+        /// Number of components in the row.
+        found: usize,
+    },
+    /// A separator is not preceded or followed by a component.
+    EmptyComponent,
+    /// The brackets are not balanced.
+    Brackets,
+}
+
+impl<E: fmt::Display> fmt::Display for ParseMintError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ParseMintError::Count { expected, found } => {
+                write!(f, "expected {} components, found {}", expected, found)
+            }
+            ParseMintError::Component { index, ref error } => {
+                write!(f, "invalid component {}: {}", index, error)
+            }
+            ParseMintError::RowCount { expected, found } => {
+                write!(f, "expected {} rows, found {}", expected, found)
+            }
+            ParseMintError::RowLength {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "expected {} components in row {}, found {}",
+                expected, row, found
+            ),
+            ParseMintError::EmptyComponent => f.write_str("empty component"),
+            ParseMintError::Brackets => f.write_str("unbalanced brackets"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Last {
+    Start,
+    Open,
+    Separator,
+    Value,
+    Close,
+}
+
+enum Token<'a> {
+    Component(&'a str),
+    /// End of a row, at a semicolon, a newline, the closing bracket
+    /// of a group without nested groups, or the end of the string.
+    RowEnd,
+}
+
+/// Calls `f` on every component, separated by commas, semicolons, whitespace,
+/// or brackets, which must be balanced, and on every possible end of a row.
+fn split<E, F>(s: &str, mut f: F) -> Result<(), ParseMintError<E>>
+where
+    F: FnMut(Token<'_>) -> Result<(), ParseMintError<E>>,
+{
+    // Bit stack of the open brackets, set for square ones.
+    let (mut brackets, mut depth) = (0u64, 0u32);
+    // Bits of the open brackets by depth, set if they contain another group.
+    let mut nested = 0u64;
+    let mut last = Last::Start;
+    let mut start = None;
+    for (i, c) in s.char_indices().chain(Some((s.len(), ' '))) {
+        let delimiter = c.is_whitespace() || ",;()[]".contains(c);
+        match (delimiter, start) {
+            (false, None) => start = Some(i),
+            (true, Some(begin)) => {
+                f(Token::Component(&s[begin..i]))?;
+                start = None;
+                last = Last::Value;
+            }
+            _ => {}
+        }
+        match c {
+            ',' | ';' => {
+                if last != Last::Value && last != Last::Close {
+                    return Err(ParseMintError::EmptyComponent);
+                }
+                last = Last::Separator;
+                if c == ';' {
+                    f(Token::RowEnd)?;
+                }
+            }
+            '\n' => f(Token::RowEnd)?,
+            '(' | '[' => {
+                if depth == 64 {
+                    return Err(ParseMintError::Brackets);
+                }
+                if depth != 0 {
+                    nested |= 1 << (depth - 1);
+                }
+                nested &= !(1 << depth);
+                brackets = brackets << 1 | (c == '[') as u64;
+                depth += 1;
+                last = Last::Open;
+            }
+            ')' | ']' => {
+                if depth == 0 || (brackets & 1 == 1) != (c == ']') {
+                    return Err(ParseMintError::Brackets);
+                }
+                if last == Last::Separator {
+                    return Err(ParseMintError::EmptyComponent);
+                }
+                brackets >>= 1;
+                depth -= 1;
+                last = Last::Close;
+                if nested & (1 << depth) == 0 {
+                    f(Token::RowEnd)?;
+                }
+            }
+            _ => {}
+        }
+    }
+    if depth != 0 {
+        return Err(ParseMintError::Brackets);
+    }
+    if last == Last::Separator {
+        return Err(ParseMintError::EmptyComponent);
+    }
+    f(Token::RowEnd)
+}
+
+/// Parses exactly `len` components into `out`.
+///
+/// If `shape` is given as rows and columns, and the string delimits
+/// more than one row, the rows must match it.
+/// On failure, the parsed components are dropped.
+unsafe fn parse_raw<T: FromStr>(
+    s: &str,
+    out: *mut T,
+    len: usize,
+    shape: Option<(usize, usize)>,
+) -> Result<(), ParseMintError<T::Err>> {
+    let mut count = 0;
+    let (mut rows, mut row_len) = (0, 0);
+    let mut row_error = None;
+    let result = split(s, |token| {
+        match token {
+            Token::Component(token) => {
+                if count < len {
+                    let value = token.parse().map_err(|error| ParseMintError::Component {
+                        index: count,
+                        error,
+                    })?;
+                    out.add(count).write(value);
+                }
+                count += 1;
+                row_len += 1;
+            }
+            Token::RowEnd if row_len != 0 => {
+                if let Some((_, columns)) = shape {
+                    if row_len != columns && row_error.is_none() {
+                        row_error = Some(ParseMintError::RowLength {
+                            row: rows,
+                            expected: columns,
+                            found: row_len,
+                        });
+                    }
+                }
+                rows += 1;
+                row_len = 0;
+            }
+            Token::RowEnd => {}
+        }
+        Ok(())
+    })
+    .and_then(|()| match shape {
+        Some((expected, _)) if rows > 1 => match row_error.take() {
+            Some(error) => Err(error),
+            None if rows != expected => Err(ParseMintError::RowCount {
+                expected,
+                found: rows,
+            }),
+            None => Ok(()),
+        },
+        _ if count != len => Err(ParseMintError::Count {
+            expected: len,
+            found: count,
+        }),
+        _ => Ok(()),
+    });
+    if result.is_err() {
+        ptr::drop_in_place(ptr::slice_from_raw_parts_mut(out, count.min(len)));
+    }
+    result
+}
+
+fn parse_array<T: FromStr, const N: usize>(s: &str) -> Result<[T; N], ParseMintError<T::Err>> {
+    let mut out = MaybeUninit::<[T; N]>::uninit();
+    unsafe {
+        parse_raw(s, out.as_mut_ptr() as *mut T, N, None)?;
+        Ok(out.assume_init())
+    }
+}
+
+fn parse_rows<T: FromStr, const R: usize, const C: usize>(
+    s: &str,
+) -> Result<[[T; C]; R], ParseMintError<T::Err>> {
+    let mut out = MaybeUninit::<[[T; C]; R]>::uninit();
+    unsafe {
+        parse_raw(s, out.as_mut_ptr() as *mut T, R * C, Some((R, C)))?;
+        Ok(out.assume_init())
+    }
+}
+
+fn fmt_components<T: fmt::Display>(f: &mut fmt::Formatter<'_>, components: &[T]) -> fmt::Result {
+    f.write_str("(")?;
+    for (i, c) in components.iter().enumerate() {
+        if i != 0 {
+            f.write_str(", ")?;
+        }
+        c.fmt(f)?;
+    }
+    f.write_str(")")
+}
+
+/// Writes the matrix row by row, on separate lines in the alternate form.
+fn fmt_matrix<T: fmt::Display>(
+    f: &mut fmt::Formatter<'_>,
+    flat: &[T],
+    (rows, columns): (usize, usize),
+    row_major: bool,
+) -> fmt::Result {
+    let alternate = f.alternate();
+    if !alternate {
+        f.write_str("[")?;
+    }
+    for r in 0..rows {
+        if r != 0 {
+            f.write_str(if alternate { "\n" } else { ", " })?;
+        }
+        f.write_str("[")?;
+        for c in 0..columns {
+            if c != 0 {
+                f.write_str(", ")?;
+            }
+            let index = if row_major {
+                r * columns + c
+            } else {
+                c * rows + r
+            };
+            flat[index].fmt(f)?;
+        }
+        f.write_str("]")?;
+    }
+    if !alternate {
+        f.write_str("]")?;
+    }
+    Ok(())
+}
+
+macro_rules! text_vector {
+    ($($name:ident : $n:expr),*) => {
+        $(
+            /// Writes the components in parentheses, such as `(1, 2, 3)`.
+            impl<T: fmt::Display> fmt::Display for $name<T> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt_components(f, AsRef::<[T; $n]>::as_ref(self))
+                }
+            }
+
+            /// Parses the components separated by commas or whitespace,
+            /// and optionally enclosed in brackets, such as `1,2,3` or `[1 2 3]`.
+            impl<T: FromStr> FromStr for $name<T> {
+                type Err = ParseMintError<T::Err>;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    parse_array::<T, $n>(s).map($name::from)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! text_matrix {
+    ($($name:ident : ($rows:expr, $columns:expr) $major:ident $($row_type:ident)*),*) => {
+        $(
+            /// Writes the rows in brackets, such as `[[1, 2], [3, 4]]`,
+            /// or each row on a separate line in the alternate form.
+            impl<T: fmt::Display> fmt::Display for $name<T> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    let flat = AsRef::<[T; $rows * $columns]>::as_ref(self);
+                    fmt_matrix(f, flat, ($rows, $columns), text_matrix!(@row_major $major))
+                }
+            }
+
+            /// Parses the components row by row, such as `[[1, 2], [3, 4]]` or `1 2; 3 4`.
+            /// Rows delimited by brackets, semicolons, or newlines must all be complete.
+            impl<T: FromStr> FromStr for $name<T> {
+                type Err = ParseMintError<T::Err>;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    parse_rows::<T, $rows, $columns>(s)
+                        $( .map($row_type::from) )*
+                        .map($name::from)
+                }
+            }
+        )*
+    };
+    (@row_major rows) => { true };
+    (@row_major columns) => { false };
+}
+
+text_vector!(
+    Vector2: 2,
+    Vector3: 3,
+    Vector4: 4,
+    Point2: 2,
+    Point3: 3,
+    Size2: 2,
+    Size3: 3,
+    Offset2: 2,
+    Quaternion: 4
+);
+
+text_matrix!(
+    RowMatrix2: (2, 2) rows,
+    RowMatrix2x3: (2, 3) rows,
+    RowMatrix2x4: (2, 4) rows,
+    RowMatrix3x2: (3, 2) rows,
+    RowMatrix3: (3, 3) rows,
+    RowMatrix3x4: (3, 4) rows,
+    RowMatrix4x2: (4, 2) rows,
+    RowMatrix4x3: (4, 3) rows,
+    RowMatrix4: (4, 4) rows,
+    ColumnMatrix2: (2, 2) columns RowMatrix2,
+    ColumnMatrix2x3: (2, 3) columns RowMatrix2x3,
+    ColumnMatrix2x4: (2, 4) columns RowMatrix2x4,
+    ColumnMatrix3x2: (3, 2) columns RowMatrix3x2,
+    ColumnMatrix3: (3, 3) columns RowMatrix3,
+    ColumnMatrix3x4: (3, 4) columns RowMatrix3x4,
+    ColumnMatrix4x2: (4, 2) columns RowMatrix4x2,
+    ColumnMatrix4x3: (4, 3) columns RowMatrix4x3,
+    ColumnMatrix4: (4, 4) columns RowMatrix4
+);
+
+/// Writes the angles in parentheses, such as `(0.1, 0.2, 0.3)`.
+impl<T: fmt::Display, B> fmt::Display for EulerAngles<T, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_components(f, &[&self.a, &self.b, &self.c])
+    }
+}
+
+/// Parses the angles separated by commas or whitespace,
+/// and optionally enclosed in brackets.
+impl<T: FromStr, B> FromStr for EulerAngles<T, B> {
+    type Err = ParseMintError<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_array::<T, 3>(s).map(EulerAngles::from)
+    }
+}
@@ -0,0 +1,159 @@
+use self::norm::{Norm, NormScalar};
+use crate::rotation::Quaternion;
+use crate::vector::{Vector2, Vector3, Vector4};
+use crate::{AsMint, AsMintMut, IntoMint};
+
+/// Value known to have unit length, such as a direction vector or a rotation quaternion.
+///
+/// The checked constructors are available for floating-point vectors and quaternions.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
+#[repr(transparent)]
+pub struct Unit<V>(V);
+
+/// Quaternion of unit length, representing a rotation.
+pub type UnitQuaternion<T> = Unit<Quaternion<T>>;
+
+impl<V> Unit<V> {
+    /// Wraps a value that is already known to have unit length, without checking it.
+    pub fn new_unchecked(value: V) -> Self {
+        Unit(value)
+    }
+
+    /// Returns the wrapped value.
+    pub fn into_inner(self) -> V {
+        self.0
+    }
+}
+
+impl<V> IntoMint for Unit<V> {
+    type MintType = Unit<V>;
+}
+
+unsafe impl<V> AsMint for Unit<V> {}
+
+unsafe impl<V> AsMintMut for Unit<V> {}
+
+impl<V> AsRef<V> for Unit<V> {
+    fn as_ref(&self) -> &V {
+        &self.0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<V> ::serde::Serialize for Unit<V>
+where
+    V: ::serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+/// Traits for checking the length of values, kept private so that
+/// the checked constructors don't commit to a public numeric trait.
+mod norm {
+    use core::ops::{Add, Mul, Sub};
+
+    /// Floating-point scalar of values that can be checked for being of unit length.
+    pub trait NormScalar:
+        Copy + PartialOrd + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+    {
+        /// Zero.
+        const ZERO: Self;
+        /// One.
+        const ONE: Self;
+        /// Default tolerance of the squared length.
+        const TOLERANCE: Self;
+        /// Returns the absolute value.
+        fn abs(self) -> Self;
+    }
+
+    macro_rules! norm_scalar {
+        ($($ty:ident),*) => {
+            $(
+                impl NormScalar for $ty {
+                    const ZERO: Self = 0.0;
+                    const ONE: Self = 1.0;
+                    const TOLERANCE: Self = $ty::EPSILON * 64.0;
+                    fn abs(self) -> Self {
+                        if self < 0.0 {
+                            -self
+                        } else {
+                            self
+                        }
+                    }
+                }
+            )*
+        };
+    }
+
+    norm_scalar!(f32, f64);
+
+    /// Value with a Euclidean length, which can be checked for being of unit length.
+    pub trait Norm {
+        /// Scalar type of the components.
+        type Scalar: NormScalar;
+        /// Returns the sum of the squared components.
+        fn norm_squared(&self) -> Self::Scalar;
+    }
+}
+
+impl<V: Norm> Unit<V> {
+    /// Wraps the value if its length is one, allowing its squared length
+    /// to differ from one by 64 machine epsilons.
+    /// Use `new_with_tolerance` for another tolerance.
+    pub fn new(value: V) -> Option<Self> {
+        Self::new_with_tolerance(value, V::Scalar::TOLERANCE)
+    }
+
+    /// Wraps the value if its squared length differs from one by no more than `tolerance`.
+    pub fn new_with_tolerance(value: V, tolerance: V::Scalar) -> Option<Self> {
+        if (value.norm_squared() - V::Scalar::ONE).abs() <= tolerance {
+            Some(Unit(value))
+        } else {
+            None
+        }
+    }
+}
+
+/// Fails if the value is not of unit length.
+#[cfg(feature = "serde")]
+impl<'de, V> ::serde::Deserialize<'de> for Unit<V>
+where
+    V: Norm + ::serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = V::deserialize(deserializer)?;
+        Unit::new(value)
+            .ok_or_else(|| <D::Error as ::serde::de::Error>::custom("value is not of unit length"))
+    }
+}
+
+macro_rules! unit {
+    ($name:ident = $fixed:ty) => {
+        impl<T> From<Unit<$name<T>>> for $name<T> {
+            fn from(unit: Unit<$name<T>>) -> Self {
+                unit.0
+            }
+        }
+
+        impl<T: NormScalar> Norm for $name<T> {
+            type Scalar = T;
+            fn norm_squared(&self) -> T {
+                let fixed: &$fixed = self.as_ref();
+                fixed.iter().fold(T::ZERO, |sum, &c| sum + c * c)
+            }
+        }
+    };
+}
+
+unit!(Vector2 = [T; 2]);
+unit!(Vector3 = [T; 3]);
+unit!(Vector4 = [T; 4]);
+unit!(Quaternion = [T; 4]);
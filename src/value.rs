@@ -0,0 +1,218 @@
+use crate::layout::MintLayout;
+use crate::matrix::*;
+use crate::rotation::*;
+use crate::vector::*;
+use core::convert::TryFrom;
+use core::fmt;
+
+macro_rules! value {
+    ($( $(#[$attr:meta])* $variant:ident : $ty:ty ),* $(,)*) => {
+        /// Discriminant of a `MintValue`, naming the mint type.
+        #[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
+        #[allow(missing_docs)]
+        pub enum MintKind {
+            $( $(#[$attr])* $variant, )*
+        }
+
+        /// Value of any vector, matrix, or rotation mint type,
+        /// for moving mint values across dynamic boundaries.
+        #[derive(Clone, Copy, Debug, Hash, PartialEq, PartialOrd, Eq, Ord)]
+        #[allow(missing_docs)]
+        pub enum MintValue<T> {
+            $( $(#[$attr])* $variant($ty), )*
+        }
+
+        impl MintKind {
+            /// All the kinds, in order.
+            pub const ALL: &'static [MintKind] = &[$( MintKind::$variant ),*];
+
+            /// Returns the name of the kind, the same as of its variant.
+            pub fn name(self) -> &'static str {
+                match self {
+                    $( MintKind::$variant => stringify!($variant), )*
+                }
+            }
+
+            /// Returns the kind with the given name.
+            pub fn from_name(name: &str) -> Option<Self> {
+                match name {
+                    $( stringify!($variant) => Some(MintKind::$variant), )*
+                    _ => None,
+                }
+            }
+
+            /// Returns the number of rows and columns. Vectors are single columns.
+            pub fn shape(self) -> (usize, usize) {
+                // Any scalar has the same shape.
+                type T = f32;
+                match self {
+                    $( MintKind::$variant => (<$ty as MintLayout>::ROWS, <$ty as MintLayout>::COLUMNS), )*
+                }
+            }
+        }
+
+        impl<T> MintValue<T> {
+            /// Returns the kind of the value.
+            pub fn kind(&self) -> MintKind {
+                match *self {
+                    $( MintValue::$variant(_) => MintKind::$variant, )*
+                }
+            }
+        }
+
+        $(
+            impl<T> From<$ty> for MintValue<T> {
+                fn from(value: $ty) -> Self {
+                    MintValue::$variant(value)
+                }
+            }
+
+            /// Fails if the value is of another kind, returning it back.
+            impl<T> TryFrom<MintValue<T>> for $ty {
+                type Error = MintValue<T>;
+
+                fn try_from(value: MintValue<T>) -> Result<Self, Self::Error> {
+                    match value {
+                        MintValue::$variant(v) => Ok(v),
+                        other => Err(other),
+                    }
+                }
+            }
+        )*
+
+        #[cfg(feature = "serde")]
+        impl<T> ::serde::Serialize for MintValue<T>
+            where T: ::serde::Serialize
+        {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where S: ::serde::Serializer
+            {
+                match *self {
+                    $( MintValue::$variant(ref v) => (MintKind::$variant, v).serialize(serializer), )*
+                }
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de, T> ::serde::de::Visitor<'de> for ValueVisitor<T>
+            where T: ::serde::Deserialize<'de>
+        {
+            type Value = MintValue<T>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a tuple of the mint kind and value")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+                where A: ::serde::de::SeqAccess<'de>
+            {
+                use ::serde::de::Error;
+                let kind = seq
+                    .next_element::<MintKind>()?
+                    .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+                let value = match kind {
+                    $( MintKind::$variant => seq.next_element::<$ty>()?.map(MintValue::$variant), )*
+                };
+                value.ok_or_else(|| A::Error::invalid_length(1, &self))
+            }
+        }
+    };
+}
+
+value! {
+    Vector2: Vector2<T>,
+    Vector3: Vector3<T>,
+    Vector4: Vector4<T>,
+    Point2: Point2<T>,
+    Point3: Point3<T>,
+    Size2: Size2<T>,
+    Size3: Size3<T>,
+    Offset2: Offset2<T>,
+    RowMatrix2: RowMatrix2<T>,
+    RowMatrix2x3: RowMatrix2x3<T>,
+    RowMatrix2x4: RowMatrix2x4<T>,
+    RowMatrix3x2: RowMatrix3x2<T>,
+    RowMatrix3: RowMatrix3<T>,
+    RowMatrix3x4: RowMatrix3x4<T>,
+    RowMatrix4x2: RowMatrix4x2<T>,
+    RowMatrix4x3: RowMatrix4x3<T>,
+    RowMatrix4: RowMatrix4<T>,
+    ColumnMatrix2: ColumnMatrix2<T>,
+    ColumnMatrix2x3: ColumnMatrix2x3<T>,
+    ColumnMatrix2x4: ColumnMatrix2x4<T>,
+    ColumnMatrix3x2: ColumnMatrix3x2<T>,
+    ColumnMatrix3: ColumnMatrix3<T>,
+    ColumnMatrix3x4: ColumnMatrix3x4<T>,
+    ColumnMatrix4x2: ColumnMatrix4x2<T>,
+    ColumnMatrix4x3: ColumnMatrix4x3<T>,
+    ColumnMatrix4: ColumnMatrix4<T>,
+    Quaternion: Quaternion<T>,
+    EulerIntraXYZ: EulerAngles<T, IntraXYZ>,
+    EulerIntraZXZ: EulerAngles<T, IntraZXZ>,
+    EulerIntraZYX: EulerAngles<T, IntraZYX>,
+    EulerExtraXYZ: EulerAngles<T, ExtraXYZ>,
+    EulerExtraZXZ: EulerAngles<T, ExtraZXZ>,
+    EulerExtraZYX: EulerAngles<T, ExtraZYX>,
+}
+
+impl fmt::Display for MintKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl ::serde::Serialize for MintKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.name())
+    }
+}
+
+#[cfg(feature = "serde")]
+struct KindVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> ::serde::de::Visitor<'de> for KindVisitor {
+    type Value = MintKind;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a mint kind name")
+    }
+
+    fn visit_str<E>(self, name: &str) -> Result<MintKind, E>
+    where
+        E: ::serde::de::Error,
+    {
+        MintKind::from_name(name)
+            .ok_or_else(|| E::invalid_value(::serde::de::Unexpected::Str(name), &self))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> ::serde::Deserialize<'de> for MintKind {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(KindVisitor)
+    }
+}
+
+#[cfg(feature = "serde")]
+struct ValueVisitor<T>(::core::marker::PhantomData<T>);
+
+#[cfg(feature = "serde")]
+impl<'de, T> ::serde::Deserialize<'de> for MintValue<T>
+where
+    T: ::serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(2, ValueVisitor(::core::marker::PhantomData))
+    }
+}
@@ -0,0 +1,80 @@
+//! Tests of the `as_ref` form of the conversion macros, which needs Rust 1.77.
+
+#![deny(
+    missing_docs,
+    rust_2018_compatibility,
+    rust_2018_idioms,
+    future_incompatible,
+    nonstandard_style,
+    unused,
+    clippy::all
+)]
+
+use mint::{AsMint, ColumnMatrix3, MintExt, Point2, RowMatrix2, Vector3};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+struct MyVec3 {
+    x: f32,
+    y: f32,
+    z: f32,
+}
+mint::impl_vector3_conversions!(MyVec3: f32, x, y, z; as_ref);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+struct Texel(u16, u16);
+mint::impl_point2_conversions!(Texel: u16, 0, 1; as_ref);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+struct MyMat3 {
+    right: MyVec3,
+    up: MyVec3,
+    back: MyVec3,
+}
+mint::impl_matrix3_conversions!(MyMat3: f32, column_major, right, up, back; as_ref);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+struct MyMat2 {
+    top: [i32; 2],
+    bottom: [i32; 2],
+}
+mint::impl_matrix2_conversions!(MyMat2: i32, row_major, top, bottom; as_ref);
+
+#[test]
+fn vectors() {
+    let mut v = MyVec3 {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+    };
+    assert_eq!(v.as_mint(), &Vector3::from([1.0, 2.0, 3.0]));
+    AsMut::<Vector3<f32>>::as_mut(&mut v).y = 5.0;
+    assert_eq!(v.y, 5.0);
+
+    let t = Texel(1, 2);
+    assert_eq!(AsRef::<Point2<u16>>::as_ref(&t), &Point2::from([1, 2]));
+}
+
+#[test]
+fn matrices() {
+    let mut m = MyMat3 {
+        right: MyVec3::from(Vector3::from([1.0, 0.0, 0.0])),
+        up: MyVec3::from(Vector3::from([0.0, 1.0, 0.0])),
+        back: MyVec3::from(Vector3::from([0.0, 0.0, 1.0])),
+    };
+    assert_eq!(AsRef::<ColumnMatrix3<f32>>::as_ref(&m), &m.into_mint());
+    AsMut::<ColumnMatrix3<f32>>::as_mut(&mut m).z.x = 2.0;
+    assert_eq!(m.back.x, 2.0);
+
+    let m = MyMat2 {
+        top: [1, 2],
+        bottom: [3, 4],
+    };
+    assert_eq!(
+        AsRef::<RowMatrix2<i32>>::as_ref(&m),
+        &RowMatrix2::from([[1, 2], [3, 4]])
+    );
+}
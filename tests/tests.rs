@@ -1,3 +1,5 @@
+//! Conversion and representation tests for the mint types.
+
 #![deny(
     missing_docs,
     rust_2018_compatibility,
@@ -8,11 +10,18 @@
     clippy::all
 )]
 
+use mint::{Aabb2, Aabb3, Frustum, Line2, Plane, Ray2, Ray3, Rect, Segment2, Segment3};
+use mint::{
+    BrownConradyDistortion, CameraIntrinsics, FisheyeDistortion, Orthographic, Sphere, Triangle3,
+};
 use mint::{
     ColumnMatrix2, ColumnMatrix2x3, ColumnMatrix2x4, ColumnMatrix3, ColumnMatrix3x2,
     ColumnMatrix3x4, ColumnMatrix4, ColumnMatrix4x2, ColumnMatrix4x3,
 };
-use mint::{EulerAngles, Point2, Point3, Quaternion, Vector2, Vector3, Vector4};
+use mint::{ColumnMatrix2A, ColumnMatrix3A, Vector2A, Vector3A};
+use mint::{EulerAngles, Rad};
+use mint::{Linear, Premultiplied, Rgb, Rgba, Srgb, Straight};
+use mint::{Offset2, Point2, Point3, Quaternion, Size2, Size3, Vector2, Vector3, Vector4};
 use mint::{
     RowMatrix2, RowMatrix2x3, RowMatrix2x4, RowMatrix3, RowMatrix3x2, RowMatrix3x4, RowMatrix4,
     RowMatrix4x2, RowMatrix4x3,
@@ -61,6 +70,10 @@ fn vector() {
     // Translation Vector <-> Point
     transitive!(Point2 [x=1, y=3] = Vector2<i32>);
     transitive!(Point3 [x=1, y=3, z=5] = Vector3<i32>);
+    transitive!(Size2 [width=1, height=3] = ref [i32; 2]);
+    transitive!(Size3 [width=1, height=3, depth=5] = ref [i32; 3]);
+    transitive!(Offset2 [x=1, y=3] = ref [i32; 2]);
+    transitive!(Offset2 [x=1, y=3] = Vector2<i32>);
 }
 
 #[test]
@@ -73,6 +86,351 @@ fn rotation() {
     assert_eq!(a1, a2);
 }
 
+#[test]
+fn angles() {
+    let e: EulerAngles<_, mint::IntraXYZ> = EulerAngles::from([Rad(1), Rad(3), Rad(5)]);
+    assert_eq!(<[i32; 3]>::from(e), [1, 3, 5]);
+    assert_eq!(<[Rad<i32>; 3]>::from(e), [Rad(1), Rad(3), Rad(5)]);
+}
+
+#[cfg(feature = "num-traits")]
+#[test]
+fn degrees() {
+    use mint::Deg;
+
+    assert_eq!(Rad::from(Deg(180.0f64)), Rad(core::f64::consts::PI));
+    assert_eq!(Deg::from(Rad(core::f64::consts::FRAC_PI_2)), Deg(90.0));
+
+    let e: EulerAngles<f64, mint::IntraXYZ> = EulerAngles::from([Deg(90.0), Deg(0.0), Deg(-180.0)]);
+    assert_eq!(e.a, core::f64::consts::FRAC_PI_2);
+    assert_eq!(e.c, -core::f64::consts::PI);
+    assert_eq!(<[Deg<f64>; 3]>::from(e), [Deg(90.0), Deg(0.0), Deg(-180.0)]);
+}
+
+#[cfg(all(feature = "serde", feature = "num-traits"))]
+#[test]
+fn serde_degrees() {
+    use mint::Deg;
+
+    let e: EulerAngles<f64, mint::IntraXYZ> = EulerAngles::from([Deg(90.0), Deg(0.0), Deg(45.0)]);
+    let mut json = Vec::new();
+    mint::serde_degrees::serialize(&e, &mut serde_json::Serializer::new(&mut json)).unwrap();
+    assert_eq!(json, b"[90.0,0.0,45.0]");
+
+    let mut deserializer = serde_json::Deserializer::from_slice(&json);
+    let e2: EulerAngles<f64, mint::IntraXYZ> =
+        mint::serde_degrees::deserialize(&mut deserializer).unwrap();
+    assert_eq!(e, e2);
+}
+
+#[test]
+fn coordinate_systems() {
+    use mint::{Reorient, Tagged, YUpLeftHanded, YUpRightHanded, ZUpLeftHanded, ZUpRightHanded};
+
+    // Blender to glTF
+    let p = Tagged::<_, ZUpRightHanded>::new(Point3::from([1, 2, 3]));
+    assert_eq!(
+        p.convert::<YUpRightHanded>().value,
+        Point3::from([1, 3, -2])
+    );
+    // Unity to glTF
+    let v = Tagged::<_, YUpLeftHanded>::new(Vector3::from([1, 2, 3]));
+    assert_eq!(
+        v.convert::<YUpRightHanded>().value,
+        Vector3::from([1, 2, -3])
+    );
+    // Unreal to Unity
+    let v = Vector3::from([1, 2, 3]).reorient::<ZUpLeftHanded, YUpLeftHanded>();
+    assert_eq!(v, Vector3::from([2, 3, 1]));
+    // round trip
+    let v = Vector3::from([1, 2, 3]);
+    assert_eq!(
+        v.reorient::<ZUpLeftHanded, ZUpRightHanded>()
+            .reorient::<ZUpRightHanded, ZUpLeftHanded>(),
+        v
+    );
+
+    // Rotation by 90 degrees around the up axis in both systems.
+    let q = Quaternion::from([0, 0, 1, 1]).reorient::<ZUpRightHanded, YUpRightHanded>();
+    assert_eq!(q, Quaternion::from([0, 1, 0, 1]));
+    let q = Quaternion::from([0, 1, 0, 1]).reorient::<YUpRightHanded, YUpLeftHanded>();
+    assert_eq!(q, Quaternion::from([0, -1, 0, 1]));
+
+    // Rotation by 90 degrees around Z in Blender, translated along Y.
+    let m = RowMatrix4::from([[0, -1, 0, 0], [1, 0, 0, 5], [0, 0, 1, 0], [0, 0, 0, 1]]);
+    let expected = RowMatrix4::from([[0, 0, 1, 0], [0, 1, 0, 0], [-1, 0, 0, -5], [0, 0, 0, 1]]);
+    assert_eq!(m.reorient::<ZUpRightHanded, YUpRightHanded>(), expected);
+    assert_eq!(
+        ColumnMatrix4::from(m).reorient::<ZUpRightHanded, YUpRightHanded>(),
+        ColumnMatrix4::from(expected)
+    );
+    assert_eq!(
+        m.truncate().reorient::<ZUpRightHanded, YUpRightHanded>(),
+        expected.truncate()
+    );
+}
+
+#[test]
+fn tagged() {
+    use mint::Tagged;
+
+    enum World {}
+    enum Screen {}
+
+    let p = Tagged::<_, World>::new(Point2::from([1, 2]));
+    let s: Tagged<_, Screen> = p.retag();
+    assert_eq!(s.into_inner(), p.value);
+    assert_eq!(p.clone(), p);
+    assert!(p < Tagged::new(Point2::from([1, 3])));
+    assert_eq!(
+        core::mem::size_of::<Tagged<ColumnMatrix4<f32>, World>>(),
+        core::mem::size_of::<ColumnMatrix4<f32>>()
+    );
+}
+
+#[test]
+fn unit() {
+    use mint::{Unit, UnitQuaternion};
+
+    let q = UnitQuaternion::new_unchecked(Quaternion::from([0, 0, 0, 1]));
+    assert_eq!(q.as_ref(), &Quaternion::from([0, 0, 0, 1]));
+    assert_eq!(Quaternion::from(q), q.into_inner());
+    let v = Unit::new_unchecked(Vector3::from([0, 1, 0]));
+    assert_eq!(Vector3::from(v), Vector3::from([0, 1, 0]));
+}
+
+#[test]
+fn unit_checked() {
+    use mint::{Unit, UnitQuaternion};
+
+    let v = Vector3::from([0.6f32, 0.8, 0.0]);
+    assert_eq!(Unit::new(v).map(Unit::into_inner), Some(v));
+    assert_eq!(Unit::new(Vector3::from([0.6f32, 0.8, 0.1])), None);
+    assert!(Unit::new_with_tolerance(Vector2::from([1.0f64, 0.1]), 0.02).is_some());
+    let q = Quaternion::from([0.5f32, 0.5, 0.5, 0.5]);
+    assert_eq!(UnitQuaternion::new(q).map(Unit::into_inner), Some(q));
+    assert_eq!(UnitQuaternion::new(Quaternion::from([0.0f32; 4])), None);
+}
+
+#[test]
+fn aabb() {
+    transitive!(Aabb2 [min=Point2::from([1, 2]), max=Point2::from([3, 4])] = ref [[i32; 2]; 2]);
+    transitive!(Aabb3 [min=Point3::from([1, 2, 3]), max=Point3::from([4, 5, 6])] = ref [[i32; 3]; 2]);
+}
+
+#[test]
+fn rect() {
+    transitive!(Rect [origin=Point2::from([1, 2]), size=Size2::from([3, 4])] = ref [[i32; 2]; 2]);
+}
+
+#[test]
+fn lines() {
+    transitive!(Ray2 [origin=Point2::from([1, 2]), direction=Vector2::from([3, 4])] = ref [[i32; 2]; 2]);
+    transitive!(Ray3 [origin=Point3::from([1, 2, 3]), direction=Vector3::from([4, 5, 6])] = ref [[i32; 3]; 2]);
+    transitive!(Segment2 [start=Point2::from([1, 2]), end=Point2::from([3, 4])] = ref [[i32; 2]; 2]);
+    transitive!(Segment3 [start=Point3::from([1, 2, 3]), end=Point3::from([4, 5, 6])] = ref [[i32; 3]; 2]);
+}
+
+#[test]
+fn planes() {
+    transitive!(Plane [normal=Vector3::from([0, 1, 0]), distance=-5] = ref [i32; 4]);
+    transitive!(Plane [normal=Vector3::from([0, 1, 0]), distance=-5] = Vector4<i32>);
+    transitive!(Line2 [normal=Vector2::from([1, 0]), distance=3] = ref [i32; 3]);
+    transitive!(Line2 [normal=Vector2::from([1, 0]), distance=3] = Vector3<i32>);
+    let plane = Plane::from(Vector4::from([1, 2, 3, 4]));
+    assert_eq!(plane.normal, Vector3::from([1, 2, 3]));
+    assert_eq!(plane.distance, 4);
+}
+
+#[test]
+fn volumes() {
+    transitive!(Sphere [center=Point3::from([1, 2, 3]), radius=4] = ref [i32; 4]);
+    transitive!(Sphere [center=Point3::from([1, 2, 3]), radius=4] = Vector4<i32>);
+    transitive!(Triangle3 [
+        a=Point3::from([1, 2, 3]),
+        b=Point3::from([4, 5, 6]),
+        c=Point3::from([7, 8, 9])]
+        = ref [[i32; 3]; 3]);
+
+    let planes = [
+        [1, 0, 0, 1],
+        [-1, 0, 0, 1],
+        [0, 1, 0, 1],
+        [0, -1, 0, 1],
+        [0, 0, -1, -1],
+        [0, 0, 1, 10],
+    ];
+    let frustum = Frustum::from(planes);
+    assert_eq!(frustum.left, Plane::from([1, 0, 0, 1]));
+    assert_eq!(frustum.far, Plane::from([0, 0, 1, 10]));
+    assert_eq!(AsRef::<[[i32; 4]; 6]>::as_ref(&frustum), &planes);
+    assert_eq!(<[[i32; 4]; 6]>::from(frustum), planes);
+}
+
+#[test]
+fn projections() {
+    transitive!(Orthographic [left=-1, right=1, bottom=-2, top=2, near=0, far=10] = ref [i32; 6]);
+}
+
+#[cfg(feature = "num-traits")]
+#[test]
+fn projection_matrices() {
+    use mint::{DepthRange, Handedness, Perspective, PerspectiveFov};
+
+    fn assert_near(a: RowMatrix4<f64>, b: [[f64; 4]; 4]) {
+        let a: [f64; 16] = a.into();
+        let b: [f64; 16] = RowMatrix4::from(b).into();
+        for (x, y) in a.iter().zip(b.iter()) {
+            assert!((x - y).abs() < 1e-12, "{:?} != {:?}", a, b);
+        }
+    }
+
+    let perspective = Perspective {
+        fov_y: Rad(core::f64::consts::FRAC_PI_2),
+        aspect: 2.0,
+        near: 1.0,
+        far: Some(3.0),
+    };
+    let gl = [
+        [0.5, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, -2.0, -3.0],
+        [0.0, 0.0, -1.0, 0.0],
+    ];
+    assert_near(
+        perspective.into_row_matrix(DepthRange::NegativeOneToOne, Handedness::Right),
+        gl,
+    );
+    let dx = [
+        [0.5, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.5, -1.5],
+        [0.0, 0.0, 1.0, 0.0],
+    ];
+    assert_near(
+        perspective.into_row_matrix(DepthRange::ZeroToOne, Handedness::Left),
+        dx,
+    );
+    assert_eq!(
+        perspective.into_column_matrix(DepthRange::ZeroToOne, Handedness::Left),
+        perspective
+            .into_row_matrix(DepthRange::ZeroToOne, Handedness::Left)
+            .into()
+    );
+    let reversed_infinite = Perspective {
+        far: None,
+        ..perspective
+    };
+    assert_near(
+        reversed_infinite.into_row_matrix(DepthRange::OneToZero, Handedness::Right),
+        [
+            [0.5, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+            [0.0, 0.0, -1.0, 0.0],
+        ],
+    );
+
+    let fov = PerspectiveFov {
+        angle_left: Rad(-core::f64::consts::FRAC_PI_4),
+        angle_right: Rad(core::f64::consts::FRAC_PI_4),
+        angle_up: Rad(core::f64::consts::FRAC_PI_4),
+        angle_down: Rad(0.0),
+        near: 1.0,
+        far: Some(3.0),
+    };
+    assert_near(
+        fov.into_row_matrix(DepthRange::NegativeOneToOne, Handedness::Right),
+        [
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 2.0, 1.0, 0.0],
+            [0.0, 0.0, -2.0, -3.0],
+            [0.0, 0.0, -1.0, 0.0],
+        ],
+    );
+
+    let ortho = Orthographic::from([-2.0, 2.0, -1.0, 1.0, 1.0, 3.0]);
+    assert_near(
+        ortho.into_row_matrix(DepthRange::NegativeOneToOne, Handedness::Right),
+        [
+            [0.5, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, -1.0, -2.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ],
+    );
+    assert_near(
+        ortho.into_row_matrix(DepthRange::ZeroToOne, Handedness::Left),
+        [
+            [0.5, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 0.5, -0.5],
+            [0.0, 0.0, 0.0, 1.0],
+        ],
+    );
+}
+
+#[test]
+fn color() {
+    let c: Rgb<u8, Srgb> = Rgb::from([255, 128, 0]);
+    assert_eq!((c.r, c.g, c.b), (255, 128, 0));
+    assert_eq!(AsRef::<[u8; 3]>::as_ref(&c), &[255, 128, 0]);
+    assert_eq!(Vector3::from(c), Vector3::from([255, 128, 0]));
+    assert_eq!(Rgb::<u8, Srgb>::from(Vector3::from([255, 128, 0])), c);
+
+    let c: Rgba<f32, Linear, Premultiplied> = Rgba::from([0.5, 0.25, 0.0, 0.5]);
+    assert_eq!(c.a, 0.5);
+    assert_eq!(<[f32; 4]>::from(c), [0.5, 0.25, 0.0, 0.5]);
+    assert_eq!(Vector4::from(c), Vector4::from([0.5, 0.25, 0.0, 0.5]));
+    let s: Rgba<f32, Srgb, Straight> = Vector4::from([1.0, 1.0, 1.0, 1.0]).into();
+    assert_eq!(AsRef::<[f32; 4]>::as_ref(&s), &[1.0; 4]);
+
+    enum DisplayP3 {}
+    let p: Rgb<u8, DisplayP3> = Rgb::from([1, 2, 3]);
+    let q = p;
+    assert_eq!(p, q);
+    assert!(p < Rgb::from([1, 2, 4]));
+}
+
+#[test]
+fn camera() {
+    transitive!(CameraIntrinsics [fx=500, fy=501, cx=320, cy=240, skew=0] = ref [i32; 5]);
+    transitive!(BrownConradyDistortion [k1=1, k2=2, p1=3, p2=4, k3=5] = ref [i32; 5]);
+    transitive!(FisheyeDistortion [k1=1, k2=2, k3=3, k4=4] = ref [i32; 4]);
+}
+
+#[cfg(feature = "num-traits")]
+#[test]
+fn camera_matrix() {
+    use core::convert::TryFrom;
+
+    let k = CameraIntrinsics {
+        fx: 500,
+        fy: 501,
+        cx: 320,
+        cy: 240,
+        skew: 1,
+    };
+    let m = RowMatrix3::from([[500, 1, 320], [0, 501, 240], [0, 0, 1]]);
+    assert_eq!(RowMatrix3::from(k), m);
+    assert_eq!(ColumnMatrix3::from(k), ColumnMatrix3::from(m));
+    assert_eq!(CameraIntrinsics::try_from(m), Ok(k));
+    assert_eq!(CameraIntrinsics::try_from(ColumnMatrix3::from(m)), Ok(k));
+
+    let not_k = RowMatrix3::from([[500, 1, 320], [0, 501, 240], [0, 1, 1]]);
+    assert_eq!(CameraIntrinsics::try_from(not_k), Err(not_k));
+}
+
+#[cfg(feature = "num-traits")]
+#[test]
+fn aabb_center_half_extent() {
+    let aabb = Aabb3::from([[-1.0f32, 0.0, 2.0], [3.0, 1.0, 2.0]]);
+    let (center, half_extent) = aabb.center_half_extent();
+    assert_eq!(center, Point3::from([1.0, 0.5, 2.0]));
+    assert_eq!(half_extent, Vector3::from([2.0, 0.5, 0.0]));
+    assert_eq!(Aabb3::from_center_half_extent(center, half_extent), aabb);
+}
+
 #[test]
 fn row_matrix() {
     matrix_transitive!(RowMatrix2 Vector2[
@@ -247,6 +605,849 @@ fn turn() {
         [4,8,12,16]]);
 }
 
+#[test]
+fn truncate() {
+    let m = RowMatrix4::from([
+        [1, 2, 3, 4],
+        [5, 6, 7, 8],
+        [9, 10, 11, 12],
+        [13, 14, 15, 16],
+    ]);
+    assert_eq!(
+        m.truncate(),
+        RowMatrix3::from([[1, 2, 3], [5, 6, 7], [9, 10, 11]])
+    );
+    assert_eq!(
+        m.truncate_affine(),
+        RowMatrix3x4::from([[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]])
+    );
+    let c = ColumnMatrix4::from(m);
+    assert_eq!(c.truncate(), ColumnMatrix3::from(m.truncate()));
+    assert_eq!(
+        c.truncate_affine(),
+        ColumnMatrix3x4::from(m.truncate_affine())
+    );
+
+    let m = RowMatrix3::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    assert_eq!(m.truncate(), RowMatrix2::from([[1, 2], [4, 5]]));
+    assert_eq!(
+        m.truncate_affine(),
+        RowMatrix2x3::from([[1, 2, 3], [4, 5, 6]])
+    );
+    let c = ColumnMatrix3::from(m);
+    assert_eq!(c.truncate(), ColumnMatrix2::from(m.truncate()));
+    assert_eq!(
+        c.truncate_affine(),
+        ColumnMatrix2x3::from(m.truncate_affine())
+    );
+}
+
+#[cfg(feature = "num-traits")]
+#[test]
+fn extend() {
+    let m = RowMatrix3::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    let expected = RowMatrix4::from([[1, 2, 3, 0], [4, 5, 6, 0], [7, 8, 9, 0], [0, 0, 0, 1]]);
+    assert_eq!(m.extend(), expected);
+    assert_eq!(
+        ColumnMatrix3::from(m).extend(),
+        ColumnMatrix4::from(expected)
+    );
+    assert_eq!(expected.truncate().extend(), expected);
+
+    let m = RowMatrix3x4::from([[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]]);
+    let expected = RowMatrix4::from([[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12], [0, 0, 0, 1]]);
+    assert_eq!(m.extend(), expected);
+    assert_eq!(
+        ColumnMatrix3x4::from(m).extend(),
+        ColumnMatrix4::from(expected)
+    );
+
+    let m = RowMatrix2x3::from([[1, 2, 3], [4, 5, 6]]);
+    let expected = RowMatrix3::from([[1, 2, 3], [4, 5, 6], [0, 0, 1]]);
+    assert_eq!(m.extend(), expected);
+    assert_eq!(
+        ColumnMatrix2x3::from(m).extend(),
+        ColumnMatrix3::from(expected)
+    );
+    assert_eq!(
+        RowMatrix2::from([[1, 2], [3, 4]]).extend(),
+        RowMatrix3::from([[1, 2, 0], [3, 4, 0], [0, 0, 1]])
+    );
+}
+
+#[test]
+fn padded() {
+    transitive!(Vector2A [x=1, y=3] = ref [i32; 2]);
+    transitive!(Vector3A [x=1, y=3, z=5] = ref [i32; 3]);
+    transitive!(Vector2A [x=1, y=3] = Vector2<i32>);
+    transitive!(Vector3A [x=1, y=3, z=5] = Vector3<i32>);
+    let v = Vector3A::from([1, 3, 5]);
+    assert_eq!(AsRef::<Vector3<i32>>::as_ref(&v), &Vector3::from([1, 3, 5]));
+
+    let m = ColumnMatrix3::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    let a = ColumnMatrix3A::from(m);
+    assert_eq!(a.y, Vector3A::from([4, 5, 6]));
+    assert_eq!(ColumnMatrix3::from(a), m);
+    assert_eq!(<[[i32; 3]; 3]>::from(a), <[[i32; 3]; 3]>::from(m));
+    let m = ColumnMatrix2::from([[1, 2], [3, 4]]);
+    assert_eq!(ColumnMatrix2::from(ColumnMatrix2A::from(m)), m);
+}
+
+#[test]
+fn padded_layout() {
+    use core::mem::{align_of, size_of};
+    assert_eq!(size_of::<Vector2A<f32>>(), 16);
+    assert_eq!(align_of::<Vector2A<f32>>(), 16);
+    assert_eq!(size_of::<Vector3A<f32>>(), 16);
+    assert_eq!(align_of::<Vector3A<f32>>(), 16);
+    assert_eq!(size_of::<ColumnMatrix2A<f32>>(), 32);
+    assert_eq!(align_of::<ColumnMatrix2A<f32>>(), 16);
+    assert_eq!(size_of::<ColumnMatrix3A<f32>>(), 48);
+    assert_eq!(align_of::<ColumnMatrix3A<f32>>(), 16);
+
+    let m = ColumnMatrix3A::from([[1.0f32, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+    let base = &m as *const _ as usize;
+    assert_eq!(&m.y as *const _ as usize - base, 16);
+    assert_eq!(&m.z.z as *const _ as usize - base, 40);
+}
+
+#[test]
+fn layout() {
+    use mint::{Major, MintLayout, ScalarKind};
+
+    fn check<M: MintLayout>(rows: usize, columns: usize, major: Major, kind: ScalarKind) {
+        assert_eq!((M::ROWS, M::COLUMNS, M::MAJOR), (rows, columns, major));
+        assert_eq!(M::COMPONENTS, rows * columns);
+        assert_eq!(M::SCALAR_KIND, kind);
+        assert_eq!(core::mem::size_of::<M>(), M::COMPONENTS * M::SCALAR_SIZE);
+    }
+
+    check::<Vector3<f32>>(3, 1, Major::Column, ScalarKind::F32);
+    check::<Point2<u16>>(2, 1, Major::Column, ScalarKind::U16);
+    check::<Quaternion<f64>>(4, 1, Major::Column, ScalarKind::F64);
+    check::<EulerAngles<f32, mint::IntraXYZ>>(3, 1, Major::Column, ScalarKind::F32);
+    check::<RowMatrix2x3<i8>>(2, 3, Major::Row, ScalarKind::I8);
+    check::<ColumnMatrix2x3<u32>>(2, 3, Major::Column, ScalarKind::U32);
+    check::<ColumnMatrix4<f32>>(4, 4, Major::Column, ScalarKind::F32);
+}
+
+#[test]
+fn flatten_slice() {
+    let mut points = [Point3::from([0, 1, 2]), Point3::from([3, 4, 5])];
+    assert_eq!(mint::flatten(&points), &[0, 1, 2, 3, 4, 5]);
+    mint::flatten_mut(&mut points)[4] = 7;
+    assert_eq!(points[1].y, 7);
+
+    let mut scalars = [0.0f32, 1.0, 2.0, 3.0];
+    let vectors = mint::unflatten::<Vector2<f32>>(&scalars).unwrap();
+    assert_eq!(
+        vectors,
+        &[Vector2::from([0.0, 1.0]), Vector2::from([2.0, 3.0])]
+    );
+    mint::unflatten_mut::<Vector4<f32>>(&mut scalars).unwrap()[0].w = 5.0;
+    assert_eq!(scalars[3], 5.0);
+    assert!(mint::unflatten::<Vector3<f32>>(&scalars).is_none());
+    assert!(mint::unflatten::<Vector3<f32>>(&[]).unwrap().is_empty());
+}
+
+#[test]
+fn flatten_arrays() {
+    let mut matrices = [RowMatrix2::from([[0, 1], [2, 3]])];
+    let arrays: &[[i32; 4]] = mint::as_arrays(&matrices).unwrap();
+    assert_eq!(arrays, &[[0, 1, 2, 3]]);
+    assert!(mint::as_arrays::<_, 2>(&matrices).is_none());
+    mint::as_arrays_mut::<_, 4>(&mut matrices).unwrap()[0][1] = 5;
+    assert_eq!(matrices[0].x.y, 5);
+
+    let mut arrays = [[0u8, 1, 2], [3, 4, 5]];
+    let vectors = mint::from_arrays::<Vector3<u8>, 3>(&arrays).unwrap();
+    assert_eq!(vectors[1], Vector3::from([3, 4, 5]));
+    assert!(mint::from_arrays::<Vector2<u8>, 3>(&arrays).is_none());
+    mint::from_arrays_mut::<Vector3<u8>, 3>(&mut arrays).unwrap()[0].z = 9;
+    assert_eq!(arrays[0][2], 9);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn flatten_vec() {
+    let points = vec![Point2::from([0.0f64, 1.0]), Point2::from([2.0, 3.0])];
+    let scalars = mint::flatten_vec(points);
+    assert_eq!(scalars, [0.0, 1.0, 2.0, 3.0]);
+    assert_eq!(scalars.capacity() % 2, 0);
+
+    let points = mint::unflatten_vec::<Point2<f64>>(scalars).unwrap();
+    assert_eq!(points[1], Point2::from([2.0, 3.0]));
+
+    let mut odd = Vec::with_capacity(4);
+    odd.extend_from_slice(&[0.0f64, 1.0, 2.0]);
+    assert_eq!(
+        mint::unflatten_vec::<Point2<f64>>(odd),
+        Err(vec![0.0, 1.0, 2.0])
+    );
+    let mut uneven = Vec::with_capacity(5);
+    uneven.extend_from_slice(&[0.0f64, 1.0]);
+    assert!(mint::unflatten_vec::<Point2<f64>>(uneven).is_err());
+}
+
+#[test]
+fn strided_slice() {
+    use mint::{StrideError, StridedSlice, StridedSliceMut};
+
+    // Interleaved position and texture coordinates, with the buffer aligned for `f32`.
+    let mut vertices = [[0.0f32; 5]; 3];
+    for (i, v) in vertices.iter_mut().enumerate() {
+        *v = [i as f32, 1.0, 2.0, 10.0 + i as f32, 11.0];
+    }
+    let bytes =
+        unsafe { core::slice::from_raw_parts_mut(vertices.as_mut_ptr() as *mut u8, 3 * 5 * 4) };
+
+    let positions = StridedSlice::<Point3<f32>>::new(bytes, 0, 20, 3).unwrap();
+    assert_eq!(positions.len(), 3);
+    assert_eq!(positions[2], Point3::from([2.0, 1.0, 2.0]));
+    assert!(positions.get(3).is_none());
+    let xs: Vec<f32> = positions.iter().map(|p| p.x).collect();
+    assert_eq!(xs, [0.0, 1.0, 2.0]);
+
+    let mut uvs = StridedSliceMut::<Vector2<f32>>::new(bytes, 12, 20, 3).unwrap();
+    uvs[1].y = 5.0;
+    for uv in uvs.iter_mut() {
+        uv.x *= 2.0;
+    }
+    let mut copied = [Vector2::from([0.0, 0.0]); 3];
+    uvs.copy_to_slice(&mut copied);
+    assert_eq!(copied[1], Vector2::from([22.0, 5.0]));
+    assert_eq!(vertices[2], [2.0, 1.0, 2.0, 24.0, 11.0]);
+
+    let bytes = unsafe { core::slice::from_raw_parts(vertices.as_ptr() as *const u8, 60) };
+    assert_eq!(
+        StridedSlice::<Point3<f32>>::new(bytes, 0, 8, 2).err(),
+        Some(StrideError::Overlapping)
+    );
+    assert_eq!(
+        StridedSlice::<Point3<f32>>::new(bytes, 12, 20, 3).err(),
+        Some(StrideError::OutOfBounds)
+    );
+    assert_eq!(
+        StridedSlice::<Point3<f32>>::new(bytes, 2, 20, 2).err(),
+        Some(StrideError::Misaligned)
+    );
+    assert_eq!(
+        StridedSlice::<Point3<f32>>::new(bytes, 0, 14, 2).err(),
+        Some(StrideError::Misaligned)
+    );
+    assert!(StridedSlice::<Point3<f32>>::new(&[], 0, 0, 0)
+        .unwrap()
+        .is_empty());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn soa() {
+    use mint::{Point3Soa, Vector2Soa};
+
+    let points = [Point3::from([0, 1, 2]), Point3::from([3, 4, 5])];
+    let mut soa = Point3Soa::from(&points[..]);
+    assert_eq!(soa.len(), 2);
+    assert_eq!(soa.as_slices().y, &[1, 4]);
+    assert_eq!(soa.get(1), Some(points[1]));
+    assert_eq!(soa.get(2), None);
+
+    soa.set(0, Point3::from([6, 7, 8]));
+    soa.as_mut_slices().z[1] = 9;
+    soa.push(Point3::from([10, 11, 12]));
+    let zipped: Vec<_> = soa.iter().collect();
+    assert_eq!(
+        zipped,
+        [
+            Point3::from([6, 7, 8]),
+            Point3::from([3, 4, 9]),
+            Point3::from([10, 11, 12]),
+        ]
+    );
+    assert_eq!(soa.to_vec(), zipped);
+    assert_eq!(soa.pop(), Some(Point3::from([10, 11, 12])));
+    assert_eq!(Vec::from(soa.clone()), &zipped[..2]);
+
+    let components = soa.into_components();
+    assert_eq!(components.x, [6, 3]);
+    assert!(Point3Soa::from_components(components).is_ok());
+
+    let uneven = Vector2 {
+        x: vec![0.0f32, 1.0],
+        y: vec![2.0],
+    };
+    assert!(Vector2Soa::from_components(uneven).is_err());
+    let soa: Vector2Soa<f32> = (0..3).map(|i| Vector2::from([i as f32, 0.0])).collect();
+    assert_eq!(soa.as_slices().x, &[0.0, 1.0, 2.0]);
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn derive_into_mint() {
+    use mint::IntoMint;
+
+    #[derive(Clone, Copy, Debug, PartialEq, IntoMint)]
+    #[mint(Vector3)]
+    struct Position<T> {
+        z: T,
+        x: T,
+        y: T,
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, IntoMint)]
+    #[mint(Point2)]
+    struct Texel {
+        #[mint(rename = "x")]
+        u: u32,
+        #[mint(rename = "y")]
+        v: u32,
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, IntoMint)]
+    #[mint(Quaternion)]
+    struct Rotation {
+        w: f32,
+        x: f32,
+        y: f32,
+        z: f32,
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, IntoMint)]
+    #[mint(Quaternion)]
+    struct Versor {
+        #[mint(rename = "x")]
+        i: f64,
+        #[mint(rename = "y")]
+        j: f64,
+        #[mint(rename = "z")]
+        k: f64,
+        #[mint(scalar)]
+        r: f64,
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, IntoMint)]
+    #[mint(ColumnMatrix2<f32>)]
+    struct Basis {
+        x: Position2,
+        y: Position2,
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, IntoMint)]
+    #[mint(mint::Vector2)]
+    struct Position2 {
+        x: f32,
+        y: f32,
+    }
+
+    fn check<M: IntoMint>(value: M) -> M::MintType {
+        value.into()
+    }
+
+    let p = Position { z: 3, x: 1, y: 2 };
+    assert_eq!(check(p), Vector3::from([1, 2, 3]));
+    assert_eq!(Position::from(Vector3::from([1, 2, 3])), p);
+
+    let t = Texel { u: 4, v: 5 };
+    assert_eq!(check(t), Point2::from([4, 5]));
+    assert_eq!(Texel::from(Point2::from([4, 5])), t);
+
+    let q = Quaternion::from([1.0, 2.0, 3.0, 4.0]);
+    let r = Rotation {
+        w: 4.0,
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+    };
+    assert_eq!(check(r), q);
+    assert_eq!(Rotation::from(q), r);
+
+    let v = Versor {
+        i: 1.0,
+        j: 2.0,
+        k: 3.0,
+        r: 4.0,
+    };
+    assert_eq!(<[f64; 4]>::from(check(v)), [1.0, 2.0, 3.0, 4.0]);
+
+    let b = Basis {
+        x: Position2 { x: 1.0, y: 0.0 },
+        y: Position2 { x: 0.0, y: 1.0 },
+    };
+    let m = check(b);
+    assert_eq!(m, ColumnMatrix2::from([[1.0, 0.0], [0.0, 1.0]]));
+    assert_eq!(Basis::from(m), b);
+}
+
+#[test]
+fn conversion_macros() {
+    use mint::IntoMint;
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct MyVec3 {
+        x: f32,
+        y: f32,
+        z: f32,
+    }
+    mint::impl_vector3_conversions!(MyVec3: f32, x, y, z);
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Texel(u16, u16);
+    mint::impl_point2_conversions!(Texel: u16, 0, 1);
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Extent<T> {
+        width: T,
+        height: T,
+    }
+    mint::impl_vector2_conversions!(<T> Extent<T>: T, width, height);
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct MyMat3 {
+        right: MyVec3,
+        up: MyVec3,
+        back: MyVec3,
+    }
+    mint::impl_matrix3_conversions!(MyMat3: f32, column_major, right, up, back);
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct MyMat2 {
+        top: [i32; 2],
+        bottom: [i32; 2],
+    }
+    mint::impl_matrix2_conversions!(MyMat2: i32, row_major, top, bottom);
+
+    fn check<M: IntoMint>(value: M) -> M::MintType {
+        value.into()
+    }
+
+    let v = MyVec3 {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+    };
+    assert_eq!(check(v), Vector3::from([1.0, 2.0, 3.0]));
+    assert_eq!(MyVec3::from(Vector3::from([1.0, 2.0, 3.0])), v);
+
+    assert_eq!(check(Texel(1, 2)), Point2::from([1, 2]));
+    assert_eq!(Texel::from(Point2::from([1, 2])), Texel(1, 2));
+
+    let e = Extent {
+        width: 3u8,
+        height: 4,
+    };
+    assert_eq!(check(e), Vector2::from([3, 4]));
+    assert_eq!(Extent::from(Vector2::from([3, 4])), e);
+
+    let m = MyMat3 {
+        right: MyVec3::from(Vector3::from([1.0, 0.0, 0.0])),
+        up: MyVec3::from(Vector3::from([0.0, 1.0, 0.0])),
+        back: MyVec3::from(Vector3::from([0.0, 0.0, 1.0])),
+    };
+    let c = check(m);
+    assert_eq!(c.y.y, 1.0);
+    assert_eq!(MyMat3::from(c), m);
+
+    let m = MyMat2 {
+        top: [1, 2],
+        bottom: [3, 4],
+    };
+    assert_eq!(check(m), RowMatrix2::from([[1, 2], [3, 4]]));
+}
+
+#[test]
+fn mint_ext() {
+    use mint::{FromMint, MintExt};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Position {
+        x: f32,
+        y: f32,
+        z: f32,
+    }
+    mint::impl_vector3_conversions!(Position: f32, x, y, z);
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Direction([f32; 3]);
+    impl mint::IntoMint for Direction {
+        type MintType = Vector3<f32>;
+    }
+    impl From<Direction> for Vector3<f32> {
+        fn from(d: Direction) -> Self {
+            d.0.into()
+        }
+    }
+    impl From<Vector3<f32>> for Direction {
+        fn from(v: Vector3<f32>) -> Self {
+            Direction(v.into())
+        }
+    }
+
+    fn rebuild<V: FromMint>(value: V) -> V {
+        V::from_mint(value.into_mint())
+    }
+
+    let p = Position {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+    };
+    assert_eq!(p.into_mint(), Vector3::from([1.0, 2.0, 3.0]));
+    assert_eq!(rebuild(p), p);
+    assert_eq!(rebuild(Vector3::from([1, 2, 3])), Vector3::from([1, 2, 3]));
+
+    let d: Direction = mint::convert(p);
+    assert_eq!(d, Direction([1.0, 2.0, 3.0]));
+    assert_eq!(mint::convert::<_, Position>(d), p);
+}
+
+#[test]
+fn as_mint() {
+    use mint::{AsMint, AsMintMut, IntoMint};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[repr(C)]
+    struct Bone {
+        columns: [[f32; 4]; 4],
+    }
+    impl IntoMint for Bone {
+        type MintType = ColumnMatrix4<f32>;
+    }
+    impl From<Bone> for ColumnMatrix4<f32> {
+        fn from(b: Bone) -> Self {
+            b.columns.into()
+        }
+    }
+    unsafe impl AsMint for Bone {}
+    unsafe impl AsMintMut for Bone {}
+
+    let identity = [
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ];
+    let mut bone = Bone { columns: identity };
+    assert_eq!(bone.as_mint(), &ColumnMatrix4::from(identity));
+    bone.as_mint_mut().w.x = 5.0;
+    assert_eq!(bone.columns[3][0], 5.0);
+
+    let mut palette = [Bone { columns: identity }; 3];
+    let matrices = mint::as_mint_slice(&palette);
+    assert_eq!(matrices.len(), 3);
+    assert_eq!(matrices[2].z.z, 1.0);
+    mint::as_mint_slice_mut(&mut palette)[1].x.y = 2.0;
+    assert_eq!(palette[1].columns[0][1], 2.0);
+
+    let mut v = Vector3::from([1, 2, 3]);
+    v.as_mint_mut().z = 4;
+    assert_eq!(v.as_mint(), &Vector3::from([1, 2, 4]));
+
+    let mut e = EulerAngles::<f32, mint::IntraXYZ>::from([0.1, 0.2, 0.3]);
+    e.as_mint_mut().c = 0.5;
+    assert_eq!(<[f32; 3]>::from(*e.as_mint()), [0.1, 0.2, 0.5]);
+}
+
+#[test]
+fn into_mints() {
+    use mint::{FromMints, IntoMints};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Position(i32, i32, i32);
+    mint::impl_point3_conversions!(Position: i32, 0, 1, 2);
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Rotation([f32; 4]);
+    impl mint::IntoMint for Rotation {
+        type MintType = Quaternion<f32>;
+    }
+    impl From<Rotation> for Quaternion<f32> {
+        fn from(r: Rotation) -> Self {
+            r.0.into()
+        }
+    }
+    impl From<Quaternion<f32>> for Rotation {
+        fn from(q: Quaternion<f32>) -> Self {
+            Rotation(q.into())
+        }
+    }
+
+    let corners = [Position(0, 0, 0), Position(1, 2, 3)];
+    let points: [Point3<i32>; 2] = corners.into_mints();
+    assert_eq!(points[1], Point3::from([1, 2, 3]));
+    assert_eq!(<[Position; 2]>::from_mints(points), corners);
+
+    let rotation = Some(Rotation([0.0, 0.0, 0.0, 1.0]));
+    assert_eq!(
+        rotation.into_mints(),
+        Some(Quaternion::from([0.0, 0.0, 0.0, 1.0]))
+    );
+    assert_eq!(Option::<Rotation>::None.into_mints(), None);
+    assert_eq!(
+        Option::<Rotation>::from_mints(rotation.into_mints()),
+        rotation
+    );
+
+    let pair = (Position(1, 1, 1), Rotation([1.0, 0.0, 0.0, 0.0]));
+    let (p, q) = pair.into_mints();
+    assert_eq!((p.x, q.v.x), (1, 1.0));
+    assert_eq!(<(Position, Rotation)>::from_mints((p, q)), pair);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn into_mints_vec() {
+    use mint::{FromMints, IntoMints};
+
+    let vectors = vec![Vector2::from([1, 2]), Vector2::from([3, 4])];
+    let mints = vectors.clone().into_mints();
+    assert_eq!(mints, vectors);
+    assert_eq!(Vec::<Vector2<i32>>::from_mints(mints), vectors);
+}
+
+#[test]
+fn mint_value() {
+    use core::convert::TryFrom;
+    use mint::{MintKind, MintValue};
+
+    let value = MintValue::from(Vector3::from([1, 2, 3]));
+    assert_eq!(value.kind(), MintKind::Vector3);
+    assert_eq!(Vector3::try_from(value), Ok(Vector3::from([1, 2, 3])));
+    assert_eq!(Point3::try_from(value), Err(value));
+
+    let euler = MintValue::from(EulerAngles::<_, mint::ExtraZYX>::from([0.5f32, 0.0, 0.0]));
+    assert_eq!(euler.kind(), MintKind::EulerExtraZYX);
+    assert!(EulerAngles::<f32, mint::IntraXYZ>::try_from(euler).is_err());
+
+    assert_eq!(MintKind::RowMatrix2x3.shape(), (2, 3));
+    assert_eq!(MintKind::ColumnMatrix4x2.shape(), (4, 2));
+    assert_eq!(MintKind::Quaternion.shape(), (4, 1));
+    for &kind in MintKind::ALL {
+        assert_eq!(MintKind::from_name(kind.name()), Some(kind));
+    }
+    assert_eq!(MintKind::from_name("Vector5"), None);
+}
+
+#[cfg(all(feature = "serde", test))]
+#[test]
+fn mint_value_serde() {
+    use mint::{MintKind, MintValue};
+
+    let value = MintValue::from(ColumnMatrix2::from([[1.0f32, 2.0], [3.0, 4.0]]));
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(json, r#"["ColumnMatrix2",[[1.0,2.0],[3.0,4.0]]]"#);
+    assert_eq!(
+        serde_json::from_str::<MintValue<f32>>(&json).ok(),
+        Some(value)
+    );
+    assert!(serde_json::from_str::<MintValue<f32>>(r#"["Vector5",[1.0]]"#).is_err());
+    assert!(serde_json::from_str::<MintValue<f32>>(r#"["Vector2",[1.0]]"#).is_err());
+    assert_eq!(
+        serde_json::from_str::<MintKind>(r#""Quaternion""#).ok(),
+        Some(MintKind::Quaternion)
+    );
+}
+
+#[test]
+fn display() {
+    let v = Vector3::from([1.0f32, 2.5, -3.0]);
+    assert_eq!(format!("{}", v), "(1, 2.5, -3)");
+    assert_eq!(format!("{:.2}", v), "(1.00, 2.50, -3.00)");
+    assert_eq!(
+        format!("{}", Quaternion::from([0, 0, 0, 1])),
+        "(0, 0, 0, 1)"
+    );
+
+    let rows = [[1, 2, 3], [4, 5, 6]];
+    let r = RowMatrix2x3::from(rows);
+    let c = ColumnMatrix2x3::from(r);
+    assert_eq!(format!("{}", r), "[[1, 2, 3], [4, 5, 6]]");
+    assert_eq!(format!("{}", c), "[[1, 2, 3], [4, 5, 6]]");
+    assert_eq!(format!("{:#}", c), "[1, 2, 3]\n[4, 5, 6]");
+}
+
+#[test]
+fn from_str() {
+    use mint::ParseMintError;
+
+    let expected = Vector3::from([1, 2, 3]);
+    for s in &["1,2,3", "(1, 2, 3)", "[1 2 3]", " 1, 2 ,3 "] {
+        assert_eq!(s.parse(), Ok(expected));
+    }
+    assert_eq!(
+        "1.5 -2 0.25".parse::<Point3<f32>>(),
+        Ok(Point3::from([1.5, -2.0, 0.25]))
+    );
+
+    assert_eq!(
+        "1,2".parse::<Vector3<i32>>(),
+        Err(ParseMintError::Count {
+            expected: 3,
+            found: 2
+        })
+    );
+    assert_eq!(
+        "1,2,3,4".parse::<Vector3<i32>>(),
+        Err(ParseMintError::Count {
+            expected: 3,
+            found: 4
+        })
+    );
+    match "1,x,3".parse::<Vector3<i32>>() {
+        Err(ParseMintError::Component { index: 1, .. }) => {}
+        other => panic!("unexpected {:?}", other),
+    }
+    assert_eq!(
+        "1,,3".parse::<Vector3<i32>>(),
+        Err(ParseMintError::EmptyComponent)
+    );
+    assert_eq!(
+        "(1, 2, 3,)".parse::<Vector3<i32>>(),
+        Err(ParseMintError::EmptyComponent)
+    );
+    assert_eq!(
+        "(1, 2, 3]".parse::<Vector3<i32>>(),
+        Err(ParseMintError::Brackets)
+    );
+    assert_eq!(
+        "(1, 2, 3".parse::<Vector3<i32>>(),
+        Err(ParseMintError::Brackets)
+    );
+    assert_eq!(
+        ParseMintError::<core::num::ParseIntError>::Count {
+            expected: 3,
+            found: 2
+        }
+        .to_string(),
+        "expected 3 components, found 2"
+    );
+
+    let rows = [[1, 2, 3], [4, 5, 6]];
+    for s in &[
+        "[[1, 2, 3], [4, 5, 6]]",
+        "1 2 3; 4 5 6",
+        "[1, 2, 3]\n[4, 5, 6]",
+    ] {
+        assert_eq!(s.parse(), Ok(RowMatrix2x3::from(rows)));
+        assert_eq!(
+            s.parse(),
+            Ok(ColumnMatrix2x3::from(RowMatrix2x3::from(rows)))
+        );
+    }
+    assert_eq!("1, 2, 3, 4, 5, 6".parse(), Ok(RowMatrix2x3::from(rows)));
+    assert_eq!(
+        "[[1, 2], [3, 4], [5, 6]]".parse::<RowMatrix2x3<i32>>(),
+        Err(ParseMintError::RowLength {
+            row: 0,
+            expected: 3,
+            found: 2
+        })
+    );
+    assert_eq!(
+        "1 2; 3 4 5 6".parse::<RowMatrix2x3<i32>>(),
+        Err(ParseMintError::RowLength {
+            row: 0,
+            expected: 3,
+            found: 2
+        })
+    );
+    assert_eq!(
+        "1 2 3\n4 5 6\n".parse::<ColumnMatrix3x2<i32>>(),
+        Err(ParseMintError::RowLength {
+            row: 0,
+            expected: 2,
+            found: 3
+        })
+    );
+    assert_eq!(
+        "[1, 2, 3]; [4, 5, 6]; [7, 8, 9]".parse::<RowMatrix2x3<i32>>(),
+        Err(ParseMintError::RowCount {
+            expected: 2,
+            found: 3
+        })
+    );
+
+    let m = ColumnMatrix4::from([
+        [1.0f64, 2.0, 3.0, 4.0],
+        [5.0, 6.0, 7.0, 8.0],
+        [9.0, 10.0, 11.0, 12.0],
+        [13.0, 14.0, 15.0, 16.0],
+    ]);
+    assert_eq!(m.to_string().parse(), Ok(m));
+    assert_eq!(format!("{:#}", m).parse(), Ok(m));
+    let e = EulerAngles::<f32, mint::IntraXYZ>::from([0.1, 0.2, 0.3]);
+    assert_eq!(e.to_string().parse(), Ok(e));
+}
+
+#[cfg(feature = "shader")]
+#[test]
+fn shader_literals() {
+    use mint::{ShaderLanguage::*, ShaderValue};
+
+    let v = Vector3::from([1.0f32, 2.5, -3.0]);
+    assert_eq!(
+        v.shader_literal(Wgsl).to_string(),
+        "vec3<f32>(1.0, 2.5, -3.0)"
+    );
+    assert_eq!(v.shader_literal(Glsl).to_string(), "vec3(1.0, 2.5, -3.0)");
+    assert_eq!(v.shader_literal(Hlsl).to_string(), "float3(1.0, 2.5, -3.0)");
+    assert_eq!(
+        Point2::from([1u32, 2]).shader_literal(Glsl).to_string(),
+        "uvec2(1u, 2u)"
+    );
+    assert_eq!(Vector4::<i32>::shader_type(Hlsl).to_string(), "int4");
+    assert_eq!(
+        Quaternion::from([0.0f32, 0.0, 0.0, 1.0])
+            .shader_literal(Wgsl)
+            .to_string(),
+        "vec4<f32>(0.0, 0.0, 0.0, 1.0)"
+    );
+
+    // Two rows and three columns, the same whatever the storage order.
+    let r = RowMatrix2x3::from([[1.0f32, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    let c = ColumnMatrix2x3::from(r);
+    for literal in &[
+        r.shader_literal(Wgsl).to_string(),
+        c.shader_literal(Wgsl).to_string(),
+    ] {
+        assert_eq!(literal, "mat3x2<f32>(1.0, 4.0, 2.0, 5.0, 3.0, 6.0)");
+    }
+    for literal in &[
+        r.shader_literal(Glsl).to_string(),
+        c.shader_literal(Glsl).to_string(),
+    ] {
+        assert_eq!(literal, "mat3x2(1.0, 4.0, 2.0, 5.0, 3.0, 6.0)");
+    }
+    for literal in &[
+        r.shader_literal(Hlsl).to_string(),
+        c.shader_literal(Hlsl).to_string(),
+    ] {
+        assert_eq!(literal, "float2x3(1.0, 2.0, 3.0, 4.0, 5.0, 6.0)");
+    }
+    assert_eq!(ColumnMatrix4::<f32>::shader_type(Glsl).to_string(), "mat4");
+    assert_eq!(
+        ColumnMatrix4::<f32>::shader_type(Wgsl).to_string(),
+        "mat4x4<f32>"
+    );
+
+    let v = Vector2::from([1.0f32, f32::NAN]);
+    assert_eq!(
+        v.shader_literal(Wgsl).to_string(),
+        format!(
+            "vec2<f32>(1.0, bitcast<f32>({:#010x}u))",
+            f32::NAN.to_bits()
+        )
+    );
+    assert_eq!(
+        v.shader_literal(Glsl).to_string(),
+        format!("vec2(1.0, uintBitsToFloat({:#010x}u))", f32::NAN.to_bits())
+    );
+    let m = ColumnMatrix2::from([[1.0f32, 0.0], [f32::NEG_INFINITY, 1.0]]);
+    assert_eq!(
+        m.shader_literal(Hlsl).to_string(),
+        "float2x2(1.0, asfloat(0xff800000u), 0.0, 1.0)"
+    );
+}
+
 #[test]
 fn vector_from_slice_success() {
     let v = Vector3::from_slice(&[0.0f32, 1.0, 2.0, 3.0]);
@@ -342,3 +1543,72 @@ representation_tests!( point3_serde => Point3<f32> : [f32; 3] );
 
 representation_tests!( euler_angles => EulerAngles<f32, f32> : [f32; 3] );
 representation_tests!( quaternions => Quaternion<f32> : [f32; 4] );
+
+representation_tests!( aabb2_serde => Aabb2<f32> : [[f32; 2]; 2] );
+representation_tests!( aabb3_serde => Aabb3<f32> : [[f32; 3]; 2] );
+representation_tests!( rect_serde => Rect<f32> : [[f32; 2]; 2] );
+representation_tests!( ray2_serde => Ray2<f32> : [[f32; 2]; 2] );
+representation_tests!( ray3_serde => Ray3<f32> : [[f32; 3]; 2] );
+representation_tests!( segment2_serde => Segment2<f32> : [[f32; 2]; 2] );
+representation_tests!( segment3_serde => Segment3<f32> : [[f32; 3]; 2] );
+representation_tests!( line2_serde => Line2<f32> : [f32; 3] );
+representation_tests!( plane_serde => Plane<f32> : [f32; 4] );
+representation_tests!( sphere_serde => Sphere<f32> : [f32; 4] );
+representation_tests!( triangle3_serde => Triangle3<f32> : [[f32; 3]; 3] );
+representation_tests!( frustum_serde => Frustum<f32> : [[f32; 4]; 6] );
+representation_tests!( orthographic_serde => Orthographic<f32> : [f32; 6] );
+representation_tests!( camera_intrinsics_serde => CameraIntrinsics<f32> : [f32; 5] );
+representation_tests!( brown_conrady_distortion_serde => BrownConradyDistortion<f32> : [f32; 5] );
+representation_tests!( fisheye_distortion_serde => FisheyeDistortion<f32> : [f32; 4] );
+representation_tests!( rgb_serde => Rgb<f32, Srgb> : [f32; 3] );
+representation_tests!( vector3a_serde => Vector3A<f32> : [f32; 3] );
+representation_tests!( column_matrix_3a_serde => ColumnMatrix3A<f32> : [[f32; 3]; 3] );
+representation_tests!( rgba_serde => Rgba<f32, Linear, Premultiplied> : [f32; 4] );
+
+representation_tests!( size2_serde => Size2<f32> : [f32; 2] );
+representation_tests!( size3_serde => Size3<f32> : [f32; 3] );
+representation_tests!( offset2_serde => Offset2<f32> : [f32; 2] );
+
+#[cfg(all(feature = "serde", test))]
+#[test]
+fn tagged_serde() {
+    let v = mint::Tagged::<_, mint::YUpRightHanded>::new(Vector3::from([1.0f32, 2.0, 3.0]));
+    let json = serde_json::to_string(&v).unwrap();
+    assert_eq!(json, serde_json::to_string(&v.value).unwrap());
+    assert_eq!(
+        serde_json::from_str::<mint::Tagged<_, _>>(&json).ok(),
+        Some(v)
+    );
+}
+
+#[cfg(all(feature = "serde", test))]
+#[test]
+fn unit_serde() {
+    use mint::Unit;
+
+    let v = Unit::new(Vector3::from([0.0f32, 0.0, 1.0])).unwrap();
+    let json = serde_json::to_string(&v).unwrap();
+    assert_eq!(json, "[0.0,0.0,1.0]");
+    assert_eq!(
+        serde_json::from_str::<Unit<Vector3<f32>>>(&json).ok(),
+        Some(v)
+    );
+    assert!(serde_json::from_str::<Unit<Vector3<f32>>>("[0.0,0.0,2.0]").is_err());
+}
+
+#[cfg(all(feature = "serde", test))]
+#[test]
+fn perspective_serde() {
+    let p = mint::Perspective {
+        fov_y: Rad(1.0f32),
+        aspect: 1.5,
+        near: 0.1,
+        far: None,
+    };
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(json, "[1.0,1.5,0.1,null]");
+    assert_eq!(
+        serde_json::from_str::<mint::Perspective<f32>>(&json).ok(),
+        Some(p)
+    );
+}